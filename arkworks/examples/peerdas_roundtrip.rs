@@ -0,0 +1,26 @@
+use kzg_bench::examples::{peerdas_roundtrip, CKzgApi};
+use kzg_bench::tests::utils::get_trusted_setup_path;
+use rust_kzg_arkworks::eip_4844::{
+    blob_to_kzg_commitment, compute_blob_kzg_proof, compute_kzg_proof, free_trusted_setup,
+    load_trusted_setup, verify_blob_kzg_proof, verify_kzg_proof,
+};
+use rust_kzg_arkworks::kzg_proofs::FFTSettings;
+use rust_kzg_arkworks::kzg_types::ArkFr;
+use rust_kzg_arkworks::utils::PolyData;
+
+fn main() {
+    let api = CKzgApi {
+        load_trusted_setup,
+        free_trusted_setup,
+        blob_to_kzg_commitment,
+        compute_blob_kzg_proof,
+        verify_blob_kzg_proof,
+        compute_kzg_proof,
+        verify_kzg_proof,
+    };
+    let timings =
+        peerdas_roundtrip::<ArkFr, PolyData, FFTSettings>(&api, &get_trusted_setup_path()).unwrap();
+    for (phase, elapsed) in timings {
+        println!("{:<40} {:>12.3?}", phase, elapsed);
+    }
+}
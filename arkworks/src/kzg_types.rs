@@ -7,7 +7,9 @@ use crate::kzg_proofs::{
     eval_poly, expand_root_of_unity, pairings_verify, FFTSettings as LFFTSettings,
     KZGSettings as LKZGSettings,
 };
-use crate::poly::{poly_fast_div, poly_inverse, poly_long_div, poly_mul_direct, poly_mul_fft};
+use crate::poly::{
+    poly_fast_div, poly_inverse, poly_long_div, poly_mul, poly_mul_direct, poly_mul_fft,
+};
 use crate::recover::{scale_poly, unscale_poly};
 use crate::utils::{
     blst_fp_into_pc_fq, blst_fr_into_pc_fr, blst_p1_into_pc_g1projective,
@@ -25,7 +27,7 @@ use ark_std::{One, Zero};
 use ark_std::UniformRand;
 
 use blst::{blst_fp, blst_fr, blst_p1};
-use kzg::common_utils::reverse_bit_order;
+use kzg::common_utils::{check_degree_bound, reverse_bit_order};
 use kzg::eip_4844::{BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1, BYTES_PER_G2};
 use kzg::msm::precompute::{precompute, PrecomputationTable};
 use kzg::{
@@ -542,6 +544,10 @@ impl Poly<ArkFr> for PolyData {
     fn mul_direct(&mut self, x: &Self, len: usize) -> Result<Self, String> {
         poly_mul_direct(self, x, len)
     }
+
+    fn mul(&mut self, x: &Self, len: usize) -> Result<Self, String> {
+        poly_mul(self, x, None, len)
+    }
 }
 
 impl FFTSettingsPoly<ArkFr, PolyData, LFFTSettings> for LFFTSettings {
@@ -575,10 +581,19 @@ impl FFTSettings<ArkFr> for LFFTSettings {
             ));
         }
 
+        Self::new_with_root(scale, &ArkFr::from_u64_arr(&SCALE2_ROOT_OF_UNITY[scale]))
+    }
+
+    fn new_with_root(scale: usize, root_of_unity: &ArkFr) -> Result<LFFTSettings, String> {
+        if scale >= SCALE2_ROOT_OF_UNITY.len() {
+            return Err(String::from(
+                "Scale is expected to be within root of unity matrix row size",
+            ));
+        }
+
         let max_width: usize = 1 << scale;
-        let root_of_unity = ArkFr::from_u64_arr(&SCALE2_ROOT_OF_UNITY[scale]);
 
-        let expanded_roots_of_unity = expand_root_of_unity(&root_of_unity, max_width)?;
+        let expanded_roots_of_unity = expand_root_of_unity(root_of_unity, max_width)?;
         let mut reverse_roots_of_unity = expanded_roots_of_unity.clone();
         reverse_roots_of_unity.reverse();
 
@@ -588,7 +603,7 @@ impl FFTSettings<ArkFr> for LFFTSettings {
 
         Ok(LFFTSettings {
             max_width,
-            root_of_unity,
+            root_of_unity: *root_of_unity,
             expanded_roots_of_unity,
             reverse_roots_of_unity,
             roots_of_unity,
@@ -616,6 +631,9 @@ impl FFTSettings<ArkFr> for LFFTSettings {
     }
 
     fn get_roots_of_unity_at(&self, i: usize) -> ArkFr {
+        if i == self.max_width {
+            return self.roots_of_unity[0];
+        }
         self.roots_of_unity[i]
     }
 
@@ -632,17 +650,30 @@ impl KZGSettings<ArkFr, ArkG1, ArkG2, LFFTSettings, PolyData, ArkFp, ArkG1Affine
         fft_settings: &LFFTSettings,
     ) -> Result<LKZGSettings, String> {
         Ok(Self {
-            secret_g1: secret_g1.to_vec(),
-            secret_g2: secret_g2.to_vec(),
+            secret_g1: secret_g1.into(),
+            secret_g2: secret_g2.into(),
             fs: fft_settings.clone(),
             precomputation: precompute(secret_g1).ok().flatten().map(Arc::new),
         })
     }
 
+    fn with_shared_points(base: &Self, with_precomputation: bool) -> Result<Self, String> {
+        let precomputation = match (&base.precomputation, with_precomputation) {
+            (_, false) => None,
+            (Some(table), true) => Some(table.clone()),
+            (None, true) => precompute(&base.secret_g1).ok().flatten().map(Arc::new),
+        };
+
+        Ok(Self {
+            fs: base.fs.clone(),
+            secret_g1: base.secret_g1.clone(),
+            secret_g2: base.secret_g2.clone(),
+            precomputation,
+        })
+    }
+
     fn commit_to_poly(&self, p: &PolyData) -> Result<ArkG1, String> {
-        if p.coeffs.len() > self.secret_g1.len() {
-            return Err(String::from("Polynomial is longer than secret g1"));
-        }
+        check_degree_bound(p.coeffs.len(), self.secret_g1.len())?;
 
         let mut out = ArkG1::default();
         g1_linear_combination(
@@ -661,6 +692,8 @@ impl KZGSettings<ArkFr, ArkG1, ArkG2, LFFTSettings, PolyData, ArkFp, ArkG1Affine
             return Err(String::from("Polynomial must not be empty"));
         }
 
+        check_degree_bound(p.coeffs.len(), self.secret_g1.len())?;
+
         // `-(x0^n)`, where `n` is `1`
         let divisor_0 = x.negate();
 
@@ -703,6 +736,8 @@ impl KZGSettings<ArkFr, ArkG1, ArkG2, LFFTSettings, PolyData, ArkFp, ArkG1Affine
             return Err(String::from("Polynomial must not be empty"));
         }
 
+        check_degree_bound(p.coeffs.len(), self.secret_g1.len())?;
+
         if !n.is_power_of_two() {
             return Err(String::from("n must be a power of two"));
         }
@@ -1,8 +1,20 @@
 #[cfg(test)]
 mod tests {
-    use kzg_bench::tests::das::{das_extension_test_known, das_extension_test_random};
+    use kzg_bench::examples::{peerdas_roundtrip, CKzgApi};
+    #[cfg(feature = "tokio")]
+    use kzg_bench::tests::das::das_extension_async_matches_sync;
+    use kzg_bench::tests::das::{
+        das_extension_test_known, das_extension_test_random, das_extension_with_explicit_root,
+        fft_settings_new_with_root_wrong_order,
+    };
+    use kzg_bench::tests::utils::get_trusted_setup_path;
+    use rust_kzg_arkworks::eip_4844::{
+        blob_to_kzg_commitment, compute_blob_kzg_proof, compute_kzg_proof, free_trusted_setup,
+        load_trusted_setup, verify_blob_kzg_proof, verify_kzg_proof,
+    };
     use rust_kzg_arkworks::kzg_proofs::FFTSettings;
     use rust_kzg_arkworks::kzg_types::ArkFr;
+    use rust_kzg_arkworks::utils::PolyData;
 
     #[test]
     fn das_extension_test_known_() {
@@ -13,4 +25,34 @@ mod tests {
     fn das_extension_test_random_() {
         das_extension_test_random::<ArkFr, FFTSettings>();
     }
+
+    #[test]
+    fn das_extension_with_explicit_root_() {
+        das_extension_with_explicit_root::<ArkFr, FFTSettings>();
+    }
+
+    #[test]
+    fn fft_settings_new_with_root_wrong_order_() {
+        fft_settings_new_with_root_wrong_order::<ArkFr, FFTSettings>();
+    }
+
+    #[test]
+    #[cfg(feature = "tokio")]
+    fn das_extension_async_matches_sync_() {
+        das_extension_async_matches_sync::<ArkFr, FFTSettings>();
+    }
+
+    #[test]
+    fn peerdas_roundtrip_() {
+        let api = CKzgApi {
+            load_trusted_setup,
+            free_trusted_setup,
+            blob_to_kzg_commitment,
+            compute_blob_kzg_proof,
+            verify_blob_kzg_proof,
+            compute_kzg_proof,
+            verify_kzg_proof,
+        };
+        peerdas_roundtrip::<ArkFr, PolyData, FFTSettings>(&api, &get_trusted_setup_path()).unwrap();
+    }
 }
@@ -2,9 +2,10 @@
 mod tests {
     use kzg_bench::tests::poly::{
         create_poly_of_length_ten, poly_div_by_zero, poly_div_fast_test, poly_div_long_test,
-        poly_div_random, poly_eval_0_check, poly_eval_check, poly_eval_nil_check,
-        poly_inverse_simple_0, poly_inverse_simple_1, poly_mul_direct_test, poly_mul_fft_test,
-        poly_mul_random, poly_test_div,
+        poly_div_random, poly_div_rem_by_zero, poly_div_rem_random, poly_eval_0_check,
+        poly_eval_check, poly_eval_nil_check, poly_inverse_simple_0, poly_inverse_simple_1,
+        poly_mul_direct_test, poly_mul_div_rem_round_trip, poly_mul_fft_test, poly_mul_random,
+        poly_pad_and_scale_by, poly_test_div,
     };
     use rust_kzg_arkworks::kzg_proofs::FFTSettings;
     use rust_kzg_arkworks::kzg_types::ArkFr;
@@ -80,4 +81,24 @@ mod tests {
     fn poly_div_fast_test_() {
         poly_div_fast_test::<ArkFr, PolyData>()
     }
+
+    #[test]
+    fn poly_mul_div_rem_round_trip_() {
+        poly_mul_div_rem_round_trip::<ArkFr, PolyData>()
+    }
+
+    #[test]
+    fn poly_div_rem_random_() {
+        poly_div_rem_random::<ArkFr, PolyData>()
+    }
+
+    #[test]
+    fn poly_div_rem_by_zero_() {
+        poly_div_rem_by_zero::<ArkFr, PolyData>()
+    }
+
+    #[test]
+    fn poly_pad_and_scale_by_() {
+        poly_pad_and_scale_by::<ArkFr, PolyData>()
+    }
 }
@@ -10,6 +10,11 @@ mod recover_tests {
         recover_simple::<Fr, FFTSettings, PolyData, PolyData>();
     }
 
+    #[test]
+    fn recover_zero_and_constant_() {
+        recover_zero_and_constant::<Fr, FFTSettings, PolyData, PolyData>();
+    }
+
     //Could be not working because of zero poly.
     #[test]
     fn recover_random_() {
@@ -20,4 +25,9 @@ mod recover_tests {
     fn more_than_half_missing_() {
         more_than_half_missing::<Fr, FFTSettings, PolyData, PolyData>();
     }
+
+    #[test]
+    fn recover_concurrent_() {
+        recover_concurrent::<Fr, FFTSettings, PolyData, PolyData>(&Vec::new);
+    }
 }
@@ -45,8 +45,8 @@ pub fn expand_root_of_unity(root: &BlstFr, width: usize) -> Result<Vec<BlstFr>,
 #[derive(Debug, Clone, Default)]
 pub struct KZGSettings {
     pub fs: FFTSettings,
-    pub secret_g1: Vec<ArkG1>,
-    pub secret_g2: Vec<ArkG2>,
+    pub secret_g1: Arc<[ArkG1]>,
+    pub secret_g2: Arc<[ArkG2]>,
     pub precomputation: Option<Arc<PrecomputationTable<ArkFr, ArkG1, ArkFp, ArkG1Affine>>>,
 }
 
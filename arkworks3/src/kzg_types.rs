@@ -4,7 +4,9 @@ use crate::kzg_proofs::{
     eval_poly, expand_root_of_unity, pairings_verify, FFTSettings as LFFTSettings,
     KZGSettings as LKZGSettings,
 };
-use crate::poly::{poly_fast_div, poly_inverse, poly_long_div, poly_mul_direct, poly_mul_fft};
+use crate::poly::{
+    poly_fast_div, poly_inverse, poly_long_div, poly_mul, poly_mul_direct, poly_mul_fft,
+};
 use crate::recover::{scale_poly, unscale_poly};
 use crate::utils::{
     blst_fp_into_pc_fq, blst_fr_into_pc_fr, blst_p1_into_pc_g1projective,
@@ -27,7 +29,7 @@ use blst::{
     blst_p1_in_g1, blst_p1_uncompress, blst_p2, blst_p2_affine, blst_p2_from_affine,
     blst_p2_uncompress, BLST_ERROR,
 };
-use kzg::common_utils::reverse_bit_order;
+use kzg::common_utils::{check_degree_bound, reverse_bit_order};
 use kzg::eip_4844::{BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1, BYTES_PER_G2};
 use kzg::msm::precompute::{precompute, PrecomputationTable};
 use kzg::{
@@ -765,6 +767,10 @@ impl Poly<ArkFr> for PolyData {
     fn mul_direct(&mut self, x: &Self, len: usize) -> Result<Self, String> {
         poly_mul_direct(self, x, len)
     }
+
+    fn mul(&mut self, x: &Self, len: usize) -> Result<Self, String> {
+        poly_mul(self, x, None, len)
+    }
 }
 
 impl FFTSettingsPoly<ArkFr, PolyData, LFFTSettings> for LFFTSettings {
@@ -798,10 +804,19 @@ impl FFTSettings<ArkFr> for LFFTSettings {
             ));
         }
 
+        Self::new_with_root(scale, &ArkFr::from_u64_arr(&SCALE2_ROOT_OF_UNITY[scale]))
+    }
+
+    fn new_with_root(scale: usize, root_of_unity: &ArkFr) -> Result<LFFTSettings, String> {
+        if scale >= SCALE2_ROOT_OF_UNITY.len() {
+            return Err(String::from(
+                "Scale is expected to be within root of unity matrix row size",
+            ));
+        }
+
         let max_width: usize = 1 << scale;
-        let root_of_unity = ArkFr::from_u64_arr(&SCALE2_ROOT_OF_UNITY[scale]);
 
-        let expanded_roots_of_unity = expand_root_of_unity(&root_of_unity, max_width)?;
+        let expanded_roots_of_unity = expand_root_of_unity(root_of_unity, max_width)?;
         let mut reverse_roots_of_unity = expanded_roots_of_unity.clone();
         reverse_roots_of_unity.reverse();
 
@@ -811,7 +826,7 @@ impl FFTSettings<ArkFr> for LFFTSettings {
 
         Ok(LFFTSettings {
             max_width,
-            root_of_unity,
+            root_of_unity: *root_of_unity,
             expanded_roots_of_unity,
             reverse_roots_of_unity,
             roots_of_unity,
@@ -839,6 +854,9 @@ impl FFTSettings<ArkFr> for LFFTSettings {
     }
 
     fn get_roots_of_unity_at(&self, i: usize) -> ArkFr {
+        if i == self.max_width {
+            return self.roots_of_unity[0];
+        }
         self.roots_of_unity[i]
     }
 
@@ -847,6 +865,38 @@ impl FFTSettings<ArkFr> for LFFTSettings {
     }
 }
 
+fn precompute_secret_g1(
+    secret_g1: &[ArkG1],
+) -> Option<Arc<PrecomputationTable<ArkFr, ArkG1, ArkFp, ArkG1Affine>>> {
+    #[cfg(feature = "sppark")]
+    {
+        use ark_bls12_381::G1Affine;
+        let points = kzg::msm::msm_impls::batch_convert::<ArkG1, ArkFp, ArkG1Affine>(secret_g1);
+        let points = unsafe {
+            alloc::slice::from_raw_parts(points.as_ptr() as *const G1Affine, points.len())
+        };
+        let prepared = rust_kzg_arkworks3_sppark::prepare_multi_scalar_mult(points);
+        Some(Arc::new(PrecomputationTable::from_ptr(prepared)))
+    }
+
+    #[cfg(feature = "sppark_wlc")]
+    {
+        let affines = kzg::msm::msm_impls::batch_convert::<ArkG1, ArkFp, ArkG1Affine>(&secret_g1);
+        let affines = unsafe {
+            alloc::slice::from_raw_parts(affines.as_ptr() as *const G1Affine, secret_g1.len())
+        };
+
+        Some(Arc::new(PrecomputationTable::from_ptr(
+            rust_kzg_arkworks3_sppark_wlc::multi_scalar_mult_init(affines).context,
+        )))
+    }
+
+    #[cfg(not(any(feature = "sppark", feature = "sppark_wlc")))]
+    {
+        precompute(secret_g1).ok().flatten().map(Arc::new)
+    }
+}
+
 impl KZGSettings<ArkFr, ArkG1, ArkG2, LFFTSettings, PolyData, ArkFp, ArkG1Affine> for LKZGSettings {
     fn new(
         secret_g1: &[ArkG1],
@@ -855,53 +905,30 @@ impl KZGSettings<ArkFr, ArkG1, ArkG2, LFFTSettings, PolyData, ArkFp, ArkG1Affine
         fft_settings: &LFFTSettings,
     ) -> Result<LKZGSettings, String> {
         Ok(Self {
-            secret_g1: secret_g1.to_vec(),
-            secret_g2: secret_g2.to_vec(),
+            secret_g1: secret_g1.into(),
+            secret_g2: secret_g2.into(),
             fs: fft_settings.clone(),
-            precomputation: {
-                #[cfg(feature = "sppark")]
-                {
-                    use ark_bls12_381::G1Affine;
-                    let points =
-                        kzg::msm::msm_impls::batch_convert::<ArkG1, ArkFp, ArkG1Affine>(secret_g1);
-                    let points = unsafe {
-                        alloc::slice::from_raw_parts(
-                            points.as_ptr() as *const G1Affine,
-                            points.len(),
-                        )
-                    };
-                    let prepared = rust_kzg_arkworks3_sppark::prepare_multi_scalar_mult(points);
-                    Some(Arc::new(PrecomputationTable::from_ptr(prepared)))
-                }
+            precomputation: precompute_secret_g1(secret_g1),
+        })
+    }
 
-                #[cfg(feature = "sppark_wlc")]
-                {
-                    let affines =
-                        kzg::msm::msm_impls::batch_convert::<ArkG1, ArkFp, ArkG1Affine>(&secret_g1);
-                    let affines = unsafe {
-                        alloc::slice::from_raw_parts(
-                            affines.as_ptr() as *const G1Affine,
-                            secret_g1.len(),
-                        )
-                    };
-
-                    Some(Arc::new(PrecomputationTable::from_ptr(
-                        rust_kzg_arkworks3_sppark_wlc::multi_scalar_mult_init(affines).context,
-                    )))
-                }
+    fn with_shared_points(base: &Self, with_precomputation: bool) -> Result<Self, String> {
+        let precomputation = match (&base.precomputation, with_precomputation) {
+            (_, false) => None,
+            (Some(table), true) => Some(table.clone()),
+            (None, true) => precompute_secret_g1(&base.secret_g1),
+        };
 
-                #[cfg(not(any(feature = "sppark", feature = "sppark_wlc")))]
-                {
-                    precompute(secret_g1).ok().flatten().map(Arc::new)
-                }
-            },
+        Ok(Self {
+            fs: base.fs.clone(),
+            secret_g1: base.secret_g1.clone(),
+            secret_g2: base.secret_g2.clone(),
+            precomputation,
         })
     }
 
     fn commit_to_poly(&self, p: &PolyData) -> Result<ArkG1, String> {
-        if p.coeffs.len() > self.secret_g1.len() {
-            return Err(String::from("Polynomial is longer than secret g1"));
-        }
+        check_degree_bound(p.coeffs.len(), self.secret_g1.len())?;
 
         let mut out = ArkG1::default();
         g1_linear_combination(
@@ -920,6 +947,8 @@ impl KZGSettings<ArkFr, ArkG1, ArkG2, LFFTSettings, PolyData, ArkFp, ArkG1Affine
             return Err(String::from("Polynomial must not be empty"));
         }
 
+        check_degree_bound(p.coeffs.len(), self.secret_g1.len())?;
+
         // `-(x0^n)`, where `n` is `1`
         let divisor_0 = x.negate();
 
@@ -962,6 +991,8 @@ impl KZGSettings<ArkFr, ArkG1, ArkG2, LFFTSettings, PolyData, ArkFp, ArkG1Affine
             return Err(String::from("Polynomial must not be empty"));
         }
 
+        check_degree_bound(p.coeffs.len(), self.secret_g1.len())?;
+
         if !n.is_power_of_two() {
             return Err(String::from("n must be a power of two"));
         }
@@ -1215,6 +1246,36 @@ impl G1AffineTrait<ArkG1, ArkFp> for ArkG1Affine {
         ArkG1(self.aff.into_projective())
     }
 
+    fn batch_scalar_mul<TFr: KzgFr>(
+        points: &[Self],
+        scalars: &[TFr],
+        n: usize,
+    ) -> Result<ArkG1, String>
+    where
+        ArkG1: G1GetFp<ArkFp> + G1Mul<TFr>,
+    {
+        use ark_ec::msm::VariableBaseMSM;
+
+        if n > points.len() || n > scalars.len() {
+            return Err(format!(
+                "Expected at least {} points and scalars, got {} and {}",
+                n,
+                points.len(),
+                scalars.len()
+            ));
+        }
+
+        let ark_points = points[0..n].iter().map(|p| p.aff).collect::<Vec<_>>();
+        let ark_scalars = scalars[0..n]
+            .iter()
+            .map(|s| BigInteger256::new(s.to_u64_arr()))
+            .collect::<Vec<_>>();
+        Ok(ArkG1(VariableBaseMSM::multi_scalar_mul(
+            ark_points.as_slice(),
+            ark_scalars.as_slice(),
+        )))
+    }
+
     fn x(&self) -> &ArkFp {
         unsafe { core::mem::transmute(&self.aff.x) }
     }
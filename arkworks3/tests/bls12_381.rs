@@ -57,6 +57,36 @@ mod tests {
         fr_uint64s_roundtrip::<ArkFr>();
     }
 
+    #[test]
+    pub fn fr_hash_to_field_works_() {
+        fr_hash_to_field_works::<ArkFr>();
+    }
+
+    #[test]
+    pub fn fr_sum_slice_and_dot_product_work_() {
+        fr_sum_slice_and_dot_product_work::<ArkFr>();
+    }
+
+    #[test]
+    pub fn g1_lincomb_c_minus_y_forms_agree_() {
+        g1_lincomb_c_minus_y_forms_agree::<ArkFr, ArkG1, ArkFp, ArkG1Affine>();
+    }
+
+    #[test]
+    pub fn fr_from_bytes_batch_works_() {
+        fr_from_bytes_batch_works::<ArkFr>();
+    }
+
+    #[test]
+    pub fn fr_write_bytes_works_() {
+        fr_write_bytes_works::<ArkFr>();
+    }
+
+    #[test]
+    pub fn g1_write_bytes_works_() {
+        g1_write_bytes_works::<ArkFr, ArkG1>();
+    }
+
     #[test]
     pub fn p1_mul_works_() {
         p1_mul_works::<ArkFr, ArkG1>();
@@ -102,6 +132,16 @@ mod tests {
         g1_random_linear_combination::<ArkFr, ArkG1, ArkFp, ArkG1Affine>(&g1_linear_combination);
     }
 
+    #[test]
+    pub fn g1_affine_batch_scalar_mul_() {
+        g1_affine_batch_scalar_mul::<ArkFr, ArkG1, ArkFp, ArkG1Affine>();
+    }
+
+    #[test]
+    pub fn g1_affine_batch_scalar_mul_short_points_() {
+        g1_affine_batch_scalar_mul_short_points::<ArkFr, ArkG1, ArkFp, ArkG1Affine>();
+    }
+
     #[test]
     pub fn pairings_work_() {
         pairings_work::<ArkFr, ArkG1, ArkG2>(&pairings_verify);
@@ -2,7 +2,8 @@
 mod tests {
     use kzg_bench::tests::consts::{
         expand_roots_is_plausible, new_fft_settings_is_plausible, roots_of_unity_are_plausible,
-        roots_of_unity_is_the_expected_size, roots_of_unity_out_of_bounds_fails,
+        roots_of_unity_boundary_indices, roots_of_unity_is_the_expected_size,
+        roots_of_unity_out_of_bounds_fails,
     };
     use rust_kzg_arkworks3::consts::SCALE2_ROOT_OF_UNITY;
     use rust_kzg_arkworks3::kzg_proofs::expand_root_of_unity;
@@ -29,6 +30,11 @@ mod tests {
         new_fft_settings_is_plausible::<ArkFr, FFTSettings>();
     }
 
+    #[test]
+    fn roots_of_unity_boundary_indices_() {
+        roots_of_unity_boundary_indices::<ArkFr, FFTSettings>();
+    }
+
     #[test]
     fn roots_of_unity_is_the_expected_size_() {
         roots_of_unity_is_the_expected_size(&SCALE2_ROOT_OF_UNITY);
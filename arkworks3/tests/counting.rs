@@ -0,0 +1,40 @@
+#[cfg(all(test, feature = "counting"))]
+mod tests {
+    use kzg_bench::tests::counting::{
+        batch_challenge_op_counts, blob_flow_op_counts, counting_delegates_and_counts,
+        field_helpers_op_counts,
+    };
+    use rust_kzg_arkworks3::eip_4844::load_trusted_setup_filename_rust;
+    use rust_kzg_arkworks3::kzg_proofs::{FFTSettings, KZGSettings};
+    use rust_kzg_arkworks3::kzg_types::{ArkFp, ArkFr, ArkG1, ArkG1Affine, ArkG2};
+    use rust_kzg_arkworks3::utils::PolyData;
+
+    #[test]
+    fn counting_delegates_and_counts_() {
+        counting_delegates_and_counts::<ArkFr, ArkG1>();
+    }
+
+    #[test]
+    fn field_helpers_op_counts_() {
+        field_helpers_op_counts::<ArkFr>();
+    }
+
+    #[test]
+    fn batch_challenge_op_counts_() {
+        batch_challenge_op_counts::<ArkFr, ArkG1>();
+    }
+
+    #[test]
+    fn blob_flow_op_counts_() {
+        blob_flow_op_counts::<
+            ArkFr,
+            ArkG1,
+            ArkG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ArkFp,
+            ArkG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+}
@@ -0,0 +1,22 @@
+#[cfg(test)]
+mod tests {
+    use kzg_bench::tests::embedded_vectors::eip_4844_embedded_vectors_test;
+    use rust_kzg_arkworks3::eip_4844::load_trusted_setup_filename_rust;
+    use rust_kzg_arkworks3::kzg_proofs::{FFTSettings, KZGSettings};
+    use rust_kzg_arkworks3::kzg_types::{ArkFp, ArkFr, ArkG1, ArkG1Affine, ArkG2};
+    use rust_kzg_arkworks3::utils::PolyData;
+
+    #[test]
+    pub fn eip_4844_embedded_vectors() {
+        eip_4844_embedded_vectors_test::<
+            ArkFr,
+            ArkG1,
+            ArkG2,
+            FFTSettings,
+            PolyData,
+            KZGSettings,
+            ArkFp,
+            ArkG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+}
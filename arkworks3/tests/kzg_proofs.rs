@@ -1,7 +1,8 @@
 #[cfg(test)]
 mod tests {
     use kzg_bench::tests::kzg_proofs::{
-        commit_to_nil_poly, commit_to_too_long_poly_returns_err, proof_multi, proof_single,
+        commit_monomial_degree_bound, commit_to_nil_poly, commit_to_too_long_poly_returns_err,
+        get_g2_generator_matches_generator, proof_multi, proof_single, with_shared_points_settings,
     };
     use rust_kzg_arkworks3::kzg_proofs::{generate_trusted_setup, FFTSettings, KZGSettings};
     use rust_kzg_arkworks3::kzg_types::{ArkFp, ArkFr, ArkG1, ArkG1Affine, ArkG2};
@@ -40,10 +41,52 @@ mod tests {
         >(&generate_trusted_setup);
     }
 
+    #[test]
+    fn commit_monomial_degree_bound_() {
+        commit_monomial_degree_bound::<
+            ArkFr,
+            ArkG1,
+            ArkG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ArkFp,
+            ArkG1Affine,
+        >(&generate_trusted_setup);
+    }
+
     #[test]
     fn proof_multi_() {
         proof_multi::<ArkFr, ArkG1, ArkG2, PolyData, FFTSettings, KZGSettings, ArkFp, ArkG1Affine>(
             &generate_trusted_setup,
         );
     }
+
+    #[test]
+    fn with_shared_points_settings_() {
+        with_shared_points_settings::<
+            ArkFr,
+            ArkG1,
+            ArkG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ArkFp,
+            ArkG1Affine,
+        >(&generate_trusted_setup);
+    }
+
+    #[test]
+    fn get_g2_generator_matches_generator_() {
+        get_g2_generator_matches_generator::<
+            ArkFr,
+            ArkG1,
+            ArkG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ArkFp,
+            ArkG1Affine,
+        >(&generate_trusted_setup);
+    }
 }
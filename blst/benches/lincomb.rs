@@ -1,5 +1,5 @@
 use criterion::{criterion_group, criterion_main, Criterion};
-use kzg_bench::benches::lincomb::bench_g1_lincomb;
+use kzg_bench::benches::lincomb::{bench_g1_lincomb, bench_g1_lincomb_affine};
 use rust_kzg_blst::kzg_proofs::g1_linear_combination;
 use rust_kzg_blst::types::fp::FsFp;
 use rust_kzg_blst::types::fr::FsFr;
@@ -9,10 +9,14 @@ fn bench_g1_lincomb_(c: &mut Criterion) {
     bench_g1_lincomb::<FsFr, FsG1, FsFp, FsG1Affine>(c, &g1_linear_combination);
 }
 
+fn bench_g1_lincomb_affine_(c: &mut Criterion) {
+    bench_g1_lincomb_affine::<FsFr, FsG1, FsFp, FsG1Affine>(c, &g1_linear_combination);
+}
+
 criterion_group! {
     name = benches;
     config = Criterion::default().sample_size(100);
-    targets = bench_g1_lincomb_
+    targets = bench_g1_lincomb_, bench_g1_lincomb_affine_
 }
 
 criterion_main!(benches);
@@ -2,6 +2,7 @@ extern crate alloc;
 
 use alloc::boxed::Box;
 use alloc::string::String;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 use core::ptr::null_mut;
 use kzg::common_utils::reverse_bit_order;
@@ -87,7 +88,7 @@ fn kzg_settings_to_rust(c_settings: &CKZGSettings) -> Result<FsKZGSettings, Stri
         core::slice::from_raw_parts(c_settings.g1_values, TRUSTED_SETUP_NUM_G1_POINTS)
             .iter()
             .map(|r| FsG1(*r))
-            .collect::<Vec<FsG1>>()
+            .collect::<Arc<[FsG1]>>()
     };
     Ok(FsKZGSettings {
         fs: fft_settings_to_rust(c_settings)?,
@@ -96,7 +97,7 @@ fn kzg_settings_to_rust(c_settings: &CKZGSettings) -> Result<FsKZGSettings, Stri
             core::slice::from_raw_parts(c_settings.g2_values, TRUSTED_SETUP_NUM_G2_POINTS)
                 .iter()
                 .map(|r| FsG2(*r))
-                .collect::<Vec<FsG2>>()
+                .collect::<Arc<[FsG2]>>()
         },
         precomputation: unsafe { PRECOMPUTATION_TABLES.get_precomputation(c_settings) },
     })
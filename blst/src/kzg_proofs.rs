@@ -22,6 +22,13 @@ impl PairingVerify<FsG1, FsG2> for FsG1 {
     }
 }
 
+#[cfg(all(
+    not(feature = "sppark"),
+    not(feature = "parallel"),
+    not(feature = "arkmsm")
+))]
+const AFFINE_PIPPENGER_THRESHOLD: usize = 128;
+
 pub fn g1_linear_combination(
     out: &mut FsG1,
     points: &[FsG1],
@@ -62,6 +69,18 @@ pub fn g1_linear_combination(
 
     #[cfg(not(feature = "sppark"))]
     {
+        // Without a precomputation table, large inputs go through blst's own affine Pippenger
+        #[cfg(all(not(feature = "parallel"), not(feature = "arkmsm")))]
+        if precomputation.is_none() && len >= AFFINE_PIPPENGER_THRESHOLD {
+            use kzg::G1Affine;
+
+            let affines = FsG1Affine::into_affines(&points[0..len]);
+            if let Ok(result) = FsG1Affine::batch_scalar_mul(&affines, scalars, len) {
+                *out = result;
+                return;
+            }
+        }
+
         *out = msm::<FsG1, FsFp, FsG1Affine, FsG1ProjAddAffine, FsFr>(
             points,
             scalars,
@@ -2,6 +2,8 @@ extern crate alloc;
 
 use alloc::string::String;
 use alloc::vec::Vec;
+#[cfg(feature = "counting")]
+use core::sync::atomic::{AtomicUsize, Ordering};
 
 use kzg::{FFTFr, Fr, PolyRecover, ZeroPoly};
 
@@ -14,11 +16,30 @@ use once_cell::sync::OnceCell;
 use rayon::prelude::*;
 
 const SCALE_FACTOR: u64 = 5;
+// Built on first use by a single caller; `get_or_init` blocks concurrent callers until the
+// table is ready, so it is never built twice.
 static INVERSE_FACTORS: OnceCell<Vec<FsFr>> = OnceCell::new();
 static UNSCALE_FACTOR_POWERS: OnceCell<Vec<FsFr>> = OnceCell::new();
 
+#[cfg(feature = "counting")]
+static INVERSE_FACTORS_BUILDS: AtomicUsize = AtomicUsize::new(0);
+#[cfg(feature = "counting")]
+static UNSCALE_FACTOR_POWERS_BUILDS: AtomicUsize = AtomicUsize::new(0);
+
+/// Number of times the inverse and the unscale factor tables were built, in that order. Each
+/// must be built at most once per process, however many threads recover at the same time.
+#[cfg(feature = "counting")]
+pub fn recovery_table_builds() -> [usize; 2] {
+    [
+        INVERSE_FACTORS_BUILDS.load(Ordering::SeqCst),
+        UNSCALE_FACTOR_POWERS_BUILDS.load(Ordering::SeqCst),
+    ]
+}
+
 pub fn scale_poly(p: &mut [FsFr], len_p: usize) {
     let factors = INVERSE_FACTORS.get_or_init(|| {
+        #[cfg(feature = "counting")]
+        INVERSE_FACTORS_BUILDS.fetch_add(1, Ordering::SeqCst);
         let scale_factor = FsFr::from_u64(SCALE_FACTOR);
         let inv_factor = FsFr::inverse(&scale_factor);
         let mut temp = Vec::with_capacity(65536);
@@ -40,6 +61,8 @@ pub fn scale_poly(p: &mut [FsFr], len_p: usize) {
 
 pub fn unscale_poly(p: &mut [FsFr], len_p: usize) {
     let factors = UNSCALE_FACTOR_POWERS.get_or_init(|| {
+        #[cfg(feature = "counting")]
+        UNSCALE_FACTOR_POWERS_BUILDS.fetch_add(1, Ordering::SeqCst);
         let scale_factor = FsFr::from_u64(SCALE_FACTOR);
         let mut temp = Vec::with_capacity(65536);
         temp.push(FsFr::one());
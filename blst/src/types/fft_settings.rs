@@ -34,12 +34,21 @@ impl FFTSettings<FsFr> for FsFFTSettings {
             ));
         }
 
+        Self::new_with_root(scale, &FsFr::from_u64_arr(&SCALE2_ROOT_OF_UNITY[scale]))
+    }
+
+    fn new_with_root(scale: usize, root_of_unity: &FsFr) -> Result<FsFFTSettings, String> {
+        if scale >= SCALE2_ROOT_OF_UNITY.len() {
+            return Err(String::from(
+                "Scale is expected to be within root of unity matrix row size",
+            ));
+        }
+
         // max_width = 2 ^ max_scale
         let max_width: usize = 1 << scale;
-        let root_of_unity = FsFr::from_u64_arr(&SCALE2_ROOT_OF_UNITY[scale]);
 
         // create max_width of roots & store them reversed as well
-        let expanded_roots_of_unity = expand_root_of_unity(&root_of_unity, max_width)?;
+        let expanded_roots_of_unity = expand_root_of_unity(root_of_unity, max_width)?;
         let mut reverse_roots_of_unity = expanded_roots_of_unity.clone();
         reverse_roots_of_unity.reverse();
 
@@ -50,7 +59,7 @@ impl FFTSettings<FsFr> for FsFFTSettings {
 
         Ok(FsFFTSettings {
             max_width,
-            root_of_unity,
+            root_of_unity: *root_of_unity,
             expanded_roots_of_unity,
             reverse_roots_of_unity,
             roots_of_unity,
@@ -78,6 +87,9 @@ impl FFTSettings<FsFr> for FsFFTSettings {
     }
 
     fn get_roots_of_unity_at(&self, i: usize) -> FsFr {
+        if i == self.max_width {
+            return self.roots_of_unity[0];
+        }
         self.roots_of_unity[i]
     }
 
@@ -3,6 +3,7 @@ extern crate alloc;
 use alloc::format;
 use alloc::string::String;
 use alloc::string::ToString;
+use alloc::vec;
 
 use blst::{
     blst_bendian_from_scalar, blst_fr, blst_fr_add, blst_fr_cneg, blst_fr_eucl_inverse,
@@ -14,6 +15,9 @@ use kzg::eip_4844::BYTES_PER_FIELD_ELEMENT;
 use kzg::Fr;
 use kzg::Scalar256;
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 #[repr(C)]
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
 pub struct FsFr(pub blst_fr);
@@ -92,6 +96,58 @@ impl Fr for FsFr {
             })
     }
 
+    // Range-checks the whole batch before writing anything, then converts it to Montgomery form
+    // in a second pass straight into `out`, so `out` is left untouched on error
+    fn from_bytes_batch(bytes: &[u8], out: &mut [Self]) -> Result<(), String> {
+        if bytes.len() != out.len() * BYTES_PER_FIELD_ELEMENT {
+            return Err(format!(
+                "Invalid byte length. Expected {}, got {}",
+                out.len() * BYTES_PER_FIELD_ELEMENT,
+                bytes.len()
+            ));
+        }
+
+        let mut scalars = vec![blst_scalar::default(); out.len()];
+        let read = |(i, (chunk, scalar)): (usize, (&[u8], &mut blst_scalar))| unsafe {
+            blst_scalar_from_bendian(scalar, chunk.as_ptr());
+            (!blst_scalar_fr_check(scalar)).then_some(i)
+        };
+
+        #[cfg(feature = "parallel")]
+        let first_invalid = bytes
+            .par_chunks(BYTES_PER_FIELD_ELEMENT)
+            .zip(scalars.par_iter_mut())
+            .enumerate()
+            .filter_map(read)
+            .min();
+
+        #[cfg(not(feature = "parallel"))]
+        let first_invalid = bytes
+            .chunks(BYTES_PER_FIELD_ELEMENT)
+            .zip(scalars.iter_mut())
+            .enumerate()
+            .find_map(read);
+
+        if let Some(i) = first_invalid {
+            return Err(format!(
+                "Invalid field element at index {}: Invalid scalar",
+                i
+            ));
+        }
+
+        let convert = |(fr, scalar): (&mut Self, &blst_scalar)| unsafe {
+            blst_fr_from_scalar(&mut fr.0, scalar);
+        };
+
+        #[cfg(feature = "parallel")]
+        out.par_iter_mut().zip(scalars.par_iter()).for_each(convert);
+
+        #[cfg(not(feature = "parallel"))]
+        out.iter_mut().zip(scalars.iter()).for_each(convert);
+
+        Ok(())
+    }
+
     fn from_hex(hex: &str) -> Result<Self, String> {
         let bytes = hex::decode(&hex[2..]).unwrap();
         Self::from_bytes(&bytes)
@@ -121,6 +177,22 @@ impl Fr for FsFr {
         bytes
     }
 
+    fn write_bytes(&self, out: &mut [u8]) -> Result<(), String> {
+        if out.len() != BYTES_PER_FIELD_ELEMENT {
+            return Err(format!(
+                "Invalid output length. Expected {}, got {}",
+                BYTES_PER_FIELD_ELEMENT,
+                out.len()
+            ));
+        }
+        let mut scalar = blst_scalar::default();
+        unsafe {
+            blst_scalar_from_fr(&mut scalar, &self.0);
+            blst_bendian_from_scalar(out.as_mut_ptr(), &scalar);
+        }
+        Ok(())
+    }
+
     fn to_u64_arr(&self) -> [u64; 4] {
         let mut val: [u64; 4] = [0; 4];
         unsafe {
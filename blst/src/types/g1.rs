@@ -5,13 +5,16 @@ use core::ptr;
 use alloc::format;
 use alloc::string::String;
 use alloc::string::ToString;
+use alloc::vec;
 use alloc::vec::Vec;
 
 use blst::p1_affines;
 use blst::{
     blst_fp, blst_p1, blst_p1_add, blst_p1_add_or_double, blst_p1_affine, blst_p1_cneg,
     blst_p1_compress, blst_p1_double, blst_p1_from_affine, blst_p1_in_g1, blst_p1_is_equal,
-    blst_p1_is_inf, blst_p1_mult, blst_p1_uncompress, blst_scalar, blst_scalar_from_fr, BLST_ERROR,
+    blst_p1_is_inf, blst_p1_mult, blst_p1_uncompress, blst_p1s_mult_pippenger,
+    blst_p1s_mult_pippenger_scratch_sizeof, blst_scalar, blst_scalar_from_bendian,
+    blst_scalar_from_fr, BLST_ERROR,
 };
 use kzg::common_utils::log_2_byte;
 use kzg::eip_4844::BYTES_PER_G1;
@@ -20,7 +23,7 @@ use kzg::G1Affine;
 use kzg::G1GetFp;
 use kzg::G1LinComb;
 use kzg::G1ProjAddAffine;
-use kzg::{G1Mul, G1};
+use kzg::{Fr, G1Mul, G1};
 
 use crate::consts::{G1_GENERATOR, G1_IDENTITY, G1_NEGATIVE_GENERATOR};
 use crate::kzg_proofs::g1_linear_combination;
@@ -94,6 +97,20 @@ impl G1 for FsG1 {
         out
     }
 
+    fn write_bytes(&self, out: &mut [u8]) -> Result<(), String> {
+        if out.len() != BYTES_PER_G1 {
+            return Err(format!(
+                "Invalid output length. Expected {}, got {}",
+                BYTES_PER_G1,
+                out.len()
+            ));
+        }
+        unsafe {
+            blst_p1_compress(out.as_mut_ptr(), &self.0);
+        }
+        Ok(())
+    }
+
     fn add_or_dbl(&self, b: &Self) -> Self {
         let mut ret = Self::default();
         unsafe {
@@ -333,6 +350,51 @@ impl G1Affine<FsG1, FsFp> for FsG1Affine {
         ret
     }
 
+    fn batch_scalar_mul<TFr: Fr>(points: &[Self], scalars: &[TFr], n: usize) -> Result<FsG1, String>
+    where
+        FsG1: G1GetFp<FsFp> + G1Mul<TFr>,
+    {
+        // Bounds the C call below, which reads `n` points
+        if n > points.len() || n > scalars.len() {
+            return Err(format!(
+                "Expected at least {} points and scalars, got {} and {}",
+                n,
+                points.len(),
+                scalars.len()
+            ));
+        }
+        let points = &points[0..n];
+        if n < 8 {
+            let mut out = FsG1::default();
+            for i in 0..n {
+                let tmp = points[i].to_proj().mul(&scalars[i]);
+                out.add_or_dbl_assign(&tmp);
+            }
+            return Ok(out);
+        }
+
+        let scalars = scalars[0..n]
+            .iter()
+            .map(|scalar| {
+                let mut ret = blst_scalar::default();
+                unsafe {
+                    blst_scalar_from_bendian(&mut ret, scalar.to_bytes().as_ptr());
+                }
+                ret
+            })
+            .collect::<Vec<_>>();
+
+        let p: [*const blst_p1_affine; 2] = [points.as_ptr() as *const blst_p1_affine, ptr::null()];
+        let s: [*const u8; 2] = [scalars.as_ptr() as *const u8, ptr::null()];
+
+        let mut ret = FsG1::default();
+        unsafe {
+            let mut scratch = vec![0u64; blst_p1s_mult_pippenger_scratch_sizeof(n) / 8];
+            blst_p1s_mult_pippenger(&mut ret.0, &p[0], n, &s[0], 255, scratch.as_mut_ptr());
+        }
+        Ok(ret)
+    }
+
     fn x(&self) -> &FsFp {
         unsafe {
             // Transmute safe due to repr(C) on FsFp
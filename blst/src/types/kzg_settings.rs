@@ -4,6 +4,7 @@ use alloc::string::String;
 use alloc::sync::Arc;
 use alloc::vec::Vec;
 
+use kzg::common_utils::check_degree_bound;
 use kzg::msm::precompute::{precompute, PrecomputationTable};
 use kzg::{FFTFr, FFTSettings, Fr, G1Mul, G2Mul, KZGSettings, Poly, G1, G2};
 
@@ -21,11 +22,31 @@ use super::g1::FsG1Affine;
 #[derive(Debug, Clone, Default)]
 pub struct FsKZGSettings {
     pub fs: FsFFTSettings,
-    pub secret_g1: Vec<FsG1>,
-    pub secret_g2: Vec<FsG2>,
+    pub secret_g1: Arc<[FsG1]>,
+    pub secret_g2: Arc<[FsG2]>,
     pub precomputation: Option<Arc<PrecomputationTable<FsFr, FsG1, FsFp, FsG1Affine>>>,
 }
 
+fn precompute_secret_g1(
+    secret_g1: &[FsG1],
+) -> Option<Arc<PrecomputationTable<FsFr, FsG1, FsFp, FsG1Affine>>> {
+    #[cfg(feature = "sppark")]
+    {
+        use blst::blst_p1_affine;
+        let points = kzg::msm::msm_impls::batch_convert::<FsG1, FsFp, FsG1Affine>(secret_g1);
+        let points = unsafe {
+            alloc::slice::from_raw_parts(points.as_ptr() as *const blst_p1_affine, points.len())
+        };
+        let prepared = rust_kzg_blst_sppark::prepare_multi_scalar_mult(points);
+        Some(Arc::new(PrecomputationTable::from_ptr(prepared)))
+    }
+
+    #[cfg(not(feature = "sppark"))]
+    {
+        precompute(secret_g1).ok().flatten().map(Arc::new)
+    }
+}
+
 impl KZGSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly, FsFp, FsG1Affine> for FsKZGSettings {
     fn new(
         secret_g1: &[FsG1],
@@ -34,37 +55,30 @@ impl KZGSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly, FsFp, FsG1Affine> for
         fft_settings: &FsFFTSettings,
     ) -> Result<Self, String> {
         Ok(Self {
-            secret_g1: secret_g1.to_vec(),
-            secret_g2: secret_g2.to_vec(),
+            secret_g1: secret_g1.into(),
+            secret_g2: secret_g2.into(),
             fs: fft_settings.clone(),
-            precomputation: {
-                #[cfg(feature = "sppark")]
-                {
-                    use blst::blst_p1_affine;
-                    let points =
-                        kzg::msm::msm_impls::batch_convert::<FsG1, FsFp, FsG1Affine>(secret_g1);
-                    let points = unsafe {
-                        alloc::slice::from_raw_parts(
-                            points.as_ptr() as *const blst_p1_affine,
-                            points.len(),
-                        )
-                    };
-                    let prepared = rust_kzg_blst_sppark::prepare_multi_scalar_mult(points);
-                    Some(Arc::new(PrecomputationTable::from_ptr(prepared)))
-                }
-
-                #[cfg(not(feature = "sppark"))]
-                {
-                    precompute(secret_g1).ok().flatten().map(Arc::new)
-                }
-            },
+            precomputation: precompute_secret_g1(secret_g1),
+        })
+    }
+
+    fn with_shared_points(base: &Self, with_precomputation: bool) -> Result<Self, String> {
+        let precomputation = match (&base.precomputation, with_precomputation) {
+            (_, false) => None,
+            (Some(table), true) => Some(table.clone()),
+            (None, true) => precompute_secret_g1(&base.secret_g1),
+        };
+
+        Ok(Self {
+            fs: base.fs.clone(),
+            secret_g1: base.secret_g1.clone(),
+            secret_g2: base.secret_g2.clone(),
+            precomputation,
         })
     }
 
     fn commit_to_poly(&self, poly: &FsPoly) -> Result<FsG1, String> {
-        if poly.coeffs.len() > self.secret_g1.len() {
-            return Err(String::from("Polynomial is longer than secret g1"));
-        }
+        check_degree_bound(poly.coeffs.len(), self.secret_g1.len())?;
 
         let mut out = FsG1::default();
         g1_linear_combination(
@@ -83,6 +97,8 @@ impl KZGSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly, FsFp, FsG1Affine> for
             return Err(String::from("Polynomial must not be empty"));
         }
 
+        check_degree_bound(p.coeffs.len(), self.secret_g1.len())?;
+
         // `-(x0^n)`, where `n` is `1`
         let divisor_0 = x.negate();
 
@@ -126,6 +142,8 @@ impl KZGSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly, FsFp, FsG1Affine> for
             return Err(String::from("Polynomial must not be empty"));
         }
 
+        check_degree_bound(p.coeffs.len(), self.secret_g1.len())?;
+
         if !n.is_power_of_two() {
             return Err(String::from("n must be a power of two"));
         }
@@ -275,6 +275,27 @@ impl Poly<FsFr> for FsPoly {
 
         Ok(ret)
     }
+
+    fn mul(&mut self, multiplier: &Self, output_len: usize) -> Result<Self, String> {
+        if self.len() < 64 || multiplier.len() < 64 || output_len < 128 {
+            // Tunable parameter
+            self.mul_direct(multiplier, output_len)
+        } else {
+            self.mul_fft(multiplier, output_len)
+        }
+    }
+
+    fn pad(&self, out_length: usize) -> Self {
+        let mut ret = Self {
+            coeffs: vec![FsFr::zero(); out_length],
+        };
+
+        for i in 0..self.len().min(out_length) {
+            ret.coeffs[i] = self.coeffs[i];
+        }
+
+        ret
+    }
 }
 
 impl FFTSettingsPoly<FsFr, FsPoly, FsFFTSettings> for FsFFTSettings {
@@ -314,18 +335,6 @@ impl FsPoly {
         }
     }
 
-    pub fn pad(&self, out_length: usize) -> Self {
-        let mut ret = Self {
-            coeffs: vec![FsFr::zero(); out_length],
-        };
-
-        for i in 0..self.len().min(out_length) {
-            ret.coeffs[i] = self.coeffs[i];
-        }
-
-        ret
-    }
-
     pub fn flip(&self) -> Result<FsPoly, String> {
         let mut ret = FsPoly {
             coeffs: vec![FsFr::default(); self.len()],
@@ -394,13 +403,4 @@ impl FsPoly {
 
         Ok(ret)
     }
-
-    pub fn mul(&mut self, multiplier: &Self, output_len: usize) -> Result<Self, String> {
-        if self.len() < 64 || multiplier.len() < 64 || output_len < 128 {
-            // Tunable parameter
-            self.mul_direct(multiplier, output_len)
-        } else {
-            self.mul_fft(multiplier, output_len)
-        }
-    }
 }
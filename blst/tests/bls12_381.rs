@@ -2,11 +2,14 @@
 mod tests {
     use kzg::common_utils::log_2_byte;
     use kzg_bench::tests::bls12_381::{
-        fr_div_by_zero, fr_div_works, fr_equal_works, fr_from_uint64_works, fr_is_null_works,
-        fr_is_one_works, fr_is_zero_works, fr_negate_works, fr_pow_works, fr_uint64s_roundtrip,
-        g1_identity_is_identity, g1_identity_is_infinity, g1_make_linear_combination,
-        g1_random_linear_combination, log_2_byte_works, p1_mul_works, p1_sub_works,
-        p2_add_or_dbl_works, p2_mul_works, p2_sub_works, pairings_work,
+        fr_div_by_zero, fr_div_works, fr_equal_works, fr_from_bytes_batch_works,
+        fr_from_uint64_works, fr_hash_to_field_works, fr_is_null_works, fr_is_one_works,
+        fr_is_zero_works, fr_negate_works, fr_pow_works, fr_sum_slice_and_dot_product_work,
+        fr_uint64s_roundtrip, fr_write_bytes_works, g1_affine_batch_scalar_mul,
+        g1_affine_batch_scalar_mul_short_points, g1_identity_is_identity, g1_identity_is_infinity,
+        g1_lincomb_c_minus_y_forms_agree, g1_make_linear_combination, g1_random_linear_combination,
+        g1_write_bytes_works, log_2_byte_works, p1_mul_works, p1_sub_works, p2_add_or_dbl_works,
+        p2_mul_works, p2_sub_works, pairings_work,
     };
 
     use rust_kzg_blst::kzg_proofs::{g1_linear_combination, pairings_verify};
@@ -70,6 +73,36 @@ mod tests {
         fr_uint64s_roundtrip::<FsFr>()
     }
 
+    #[test]
+    fn fr_hash_to_field_works_() {
+        fr_hash_to_field_works::<FsFr>()
+    }
+
+    #[test]
+    fn fr_sum_slice_and_dot_product_work_() {
+        fr_sum_slice_and_dot_product_work::<FsFr>()
+    }
+
+    #[test]
+    fn g1_lincomb_c_minus_y_forms_agree_() {
+        g1_lincomb_c_minus_y_forms_agree::<FsFr, FsG1, FsFp, FsG1Affine>()
+    }
+
+    #[test]
+    fn fr_from_bytes_batch_works_() {
+        fr_from_bytes_batch_works::<FsFr>()
+    }
+
+    #[test]
+    fn fr_write_bytes_works_() {
+        fr_write_bytes_works::<FsFr>()
+    }
+
+    #[test]
+    fn g1_write_bytes_works_() {
+        g1_write_bytes_works::<FsFr, FsG1>()
+    }
+
     #[test]
     fn p1_mul_works_() {
         p1_mul_works::<FsFr, FsG1>()
@@ -115,6 +148,16 @@ mod tests {
         g1_random_linear_combination::<FsFr, FsG1, FsFp, FsG1Affine>(&g1_linear_combination)
     }
 
+    #[test]
+    fn g1_affine_batch_scalar_mul_() {
+        g1_affine_batch_scalar_mul::<FsFr, FsG1, FsFp, FsG1Affine>();
+    }
+
+    #[test]
+    fn g1_affine_batch_scalar_mul_short_points_() {
+        g1_affine_batch_scalar_mul_short_points::<FsFr, FsG1, FsFp, FsG1Affine>();
+    }
+
     #[test]
     fn pairings_work_() {
         pairings_work::<FsFr, FsG1, FsG2>(&pairings_verify)
@@ -0,0 +1,30 @@
+#[cfg(test)]
+mod tests {
+    use kzg_bench::tests::consistency::{
+        das_outputs_match_reference_test, eip_4844_outputs_match_reference_test,
+    };
+    use rust_kzg_blst::eip_4844::load_trusted_setup_filename_rust;
+    use rust_kzg_blst::types::{
+        fft_settings::FsFFTSettings, fp::FsFp, fr::FsFr, g1::FsG1, g1::FsG1Affine, g2::FsG2,
+        kzg_settings::FsKZGSettings, poly::FsPoly,
+    };
+
+    #[test]
+    pub fn eip_4844_outputs_match_reference() {
+        eip_4844_outputs_match_reference_test::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsFFTSettings,
+            FsPoly,
+            FsKZGSettings,
+            FsFp,
+            FsG1Affine,
+        >("blst", &load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn das_outputs_match_reference() {
+        das_outputs_match_reference_test::<FsFr, FsFFTSettings, FsPoly>("blst");
+    }
+}
@@ -5,7 +5,8 @@
 mod tests {
     use kzg_bench::tests::consts::{
         expand_roots_is_plausible, new_fft_settings_is_plausible, roots_of_unity_are_plausible,
-        roots_of_unity_is_the_expected_size, roots_of_unity_out_of_bounds_fails,
+        roots_of_unity_boundary_indices, roots_of_unity_is_the_expected_size,
+        roots_of_unity_out_of_bounds_fails,
     };
     use rust_kzg_blst::consts::SCALE2_ROOT_OF_UNITY;
     use rust_kzg_blst::types::fft_settings::{expand_root_of_unity, FsFFTSettings};
@@ -37,6 +38,11 @@ mod tests {
         new_fft_settings_is_plausible::<FsFr, FsFFTSettings>();
     }
 
+    #[test]
+    fn roots_of_unity_boundary_indices_() {
+        roots_of_unity_boundary_indices::<FsFr, FsFFTSettings>();
+    }
+
     // Local tests
     // #[test]
     // fn roots_of_unity_repeat_at_stride_() {
@@ -0,0 +1,44 @@
+#[cfg(all(test, feature = "counting"))]
+mod tests {
+    use kzg_bench::tests::counting::{
+        batch_challenge_op_counts, blob_flow_op_counts, counting_delegates_and_counts,
+        field_helpers_op_counts,
+    };
+    use rust_kzg_blst::eip_4844::load_trusted_setup_filename_rust;
+    use rust_kzg_blst::types::fft_settings::FsFFTSettings;
+    use rust_kzg_blst::types::fp::FsFp;
+    use rust_kzg_blst::types::fr::FsFr;
+    use rust_kzg_blst::types::g1::{FsG1, FsG1Affine};
+    use rust_kzg_blst::types::g2::FsG2;
+    use rust_kzg_blst::types::kzg_settings::FsKZGSettings;
+    use rust_kzg_blst::types::poly::FsPoly;
+
+    #[test]
+    fn counting_delegates_and_counts_() {
+        counting_delegates_and_counts::<FsFr, FsG1>();
+    }
+
+    #[test]
+    fn field_helpers_op_counts_() {
+        field_helpers_op_counts::<FsFr>();
+    }
+
+    #[test]
+    fn batch_challenge_op_counts_() {
+        batch_challenge_op_counts::<FsFr, FsG1>();
+    }
+
+    #[test]
+    fn blob_flow_op_counts_() {
+        blob_flow_op_counts::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+            FsFp,
+            FsG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+}
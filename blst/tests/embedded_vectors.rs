@@ -0,0 +1,23 @@
+#[cfg(test)]
+mod tests {
+    use kzg_bench::tests::embedded_vectors::eip_4844_embedded_vectors_test;
+    use rust_kzg_blst::eip_4844::load_trusted_setup_filename_rust;
+    use rust_kzg_blst::types::{
+        fft_settings::FsFFTSettings, fp::FsFp, fr::FsFr, g1::FsG1, g1::FsG1Affine, g2::FsG2,
+        kzg_settings::FsKZGSettings, poly::FsPoly,
+    };
+
+    #[test]
+    pub fn eip_4844_embedded_vectors() {
+        eip_4844_embedded_vectors_test::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsFFTSettings,
+            FsPoly,
+            FsKZGSettings,
+            FsFp,
+            FsG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+}
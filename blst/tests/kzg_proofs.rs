@@ -6,7 +6,8 @@ mod tests {
     };
     use kzg::G1;
     use kzg_bench::tests::kzg_proofs::{
-        commit_to_nil_poly, commit_to_too_long_poly_returns_err, proof_multi, proof_single,
+        commit_monomial_degree_bound, commit_to_nil_poly, commit_to_too_long_poly_returns_err,
+        get_g2_generator_matches_generator, proof_multi, proof_single, with_shared_points_settings,
     };
 
     use rust_kzg_blst::types::fft_settings::FsFFTSettings;
@@ -53,6 +54,20 @@ mod tests {
         >(&generate_trusted_setup);
     }
 
+    #[test]
+    pub fn test_commit_monomial_degree_bound() {
+        commit_monomial_degree_bound::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+            FsFp,
+            FsG1Affine,
+        >(&generate_trusted_setup);
+    }
+
     #[test]
     pub fn test_proof_multi() {
         proof_multi::<FsFr, FsG1, FsG2, FsPoly, FsFFTSettings, FsKZGSettings, FsFp, FsG1Affine>(
@@ -112,4 +127,32 @@ mod tests {
             og_pairings_verify();
         }
     }
+
+    #[test]
+    pub fn test_with_shared_points_settings() {
+        with_shared_points_settings::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+            FsFp,
+            FsG1Affine,
+        >(&generate_trusted_setup);
+    }
+
+    #[test]
+    pub fn test_get_g2_generator_matches_generator() {
+        get_g2_generator_matches_generator::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+            FsFp,
+            FsG1Affine,
+        >(&generate_trusted_setup);
+    }
 }
@@ -17,6 +17,11 @@ mod tests {
         recover_simple::<FsFr, FsFFTSettings, FsPoly, FsPoly>();
     }
 
+    #[test]
+    fn recover_zero_and_constant_() {
+        recover_zero_and_constant::<FsFr, FsFFTSettings, FsPoly, FsPoly>();
+    }
+
     #[test]
     fn recover_random_() {
         recover_random::<FsFr, FsFFTSettings, FsPoly, FsPoly>();
@@ -26,4 +31,20 @@ mod tests {
     fn more_than_half_missing_() {
         more_than_half_missing::<FsFr, FsFFTSettings, FsPoly, FsPoly>();
     }
+
+    #[test]
+    fn recover_concurrent_() {
+        recover_concurrent::<FsFr, FsFFTSettings, FsPoly, FsPoly>(&table_builds);
+    }
+
+    #[cfg(feature = "counting")]
+    fn table_builds() -> Vec<usize> {
+        rust_kzg_blst::recovery::recovery_table_builds().to_vec()
+    }
+
+    // The tables are only counted with the counting feature
+    #[cfg(not(feature = "counting"))]
+    fn table_builds() -> Vec<usize> {
+        Vec::new()
+    }
 }
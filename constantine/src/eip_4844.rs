@@ -2,6 +2,7 @@ extern crate alloc;
 
 use alloc::boxed::Box;
 use alloc::string::String;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 use core::ptr::null_mut;
 use kzg::common_utils::reverse_bit_order;
@@ -86,7 +87,7 @@ fn kzg_settings_to_rust(c_settings: &CKZGSettings) -> Result<CtKZGSettings, Stri
         core::slice::from_raw_parts(c_settings.g1_values, TRUSTED_SETUP_NUM_G1_POINTS)
             .iter()
             .map(|r| CtG1::from_blst_p1(*r))
-            .collect::<Vec<CtG1>>()
+            .collect::<Arc<[CtG1]>>()
     };
     Ok(CtKZGSettings {
         fs: fft_settings_to_rust(c_settings)?,
@@ -95,7 +96,7 @@ fn kzg_settings_to_rust(c_settings: &CKZGSettings) -> Result<CtKZGSettings, Stri
             core::slice::from_raw_parts(c_settings.g2_values, TRUSTED_SETUP_NUM_G2_POINTS)
                 .iter()
                 .map(|r| CtG2::from_blst_p2(*r))
-                .collect::<Vec<CtG2>>()
+                .collect::<Arc<[CtG2]>>()
         },
         precomputation: None,
     })
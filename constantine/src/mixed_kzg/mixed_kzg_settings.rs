@@ -123,6 +123,15 @@ impl KZGSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtFp, CtG1Affine> for
         MixedKzgSettings::new(secret_g1, secret_g2, length, fs)
     }
 
+    fn with_shared_points(base: &Self, with_precomputation: bool) -> Result<Self, String> {
+        match base {
+            MixedKzgSettings::Constantine(_) => Err("Context not in generic format".to_string()),
+            MixedKzgSettings::Generic(generic_context) => Ok(MixedKzgSettings::Generic(
+                GenericContext::with_shared_points(generic_context, with_precomputation)?,
+            )),
+        }
+    }
+
     fn commit_to_poly(&self, p: &CtPoly) -> Result<CtG1, String> {
         match self {
             MixedKzgSettings::Constantine(_) => Err("Context not in generic format".to_string()),
@@ -4,6 +4,7 @@ extern crate alloc;
 use alloc::format;
 use alloc::string::String;
 use alloc::string::ToString;
+use alloc::vec;
 
 use blst::blst_fr;
 use constantine::ctt_codec_scalar_status;
@@ -19,6 +20,9 @@ use constantine_sys::bls12_381_fr;
 use crate::utils::ptr_transmute;
 use crate::utils::ptr_transmute_mut;
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 #[repr(C)]
 #[derive(Clone, Copy, Default)]
 pub struct CtFr(pub bls12_381_fr);
@@ -136,6 +140,59 @@ impl Fr for CtFr {
             })
     }
 
+    // Range-checks the whole batch before writing anything, then converts it to Montgomery form
+    // in a second pass straight into `out`, so `out` is left untouched on error
+    fn from_bytes_batch(bytes: &[u8], out: &mut [Self]) -> Result<(), String> {
+        if bytes.len() != out.len() * BYTES_PER_FIELD_ELEMENT {
+            return Err(format!(
+                "Invalid byte length. Expected {}, got {}",
+                out.len() * BYTES_PER_FIELD_ELEMENT,
+                bytes.len()
+            ));
+        }
+
+        let mut scalars = vec![constantine::big255::default(); out.len()];
+        let read = |(i, (chunk, scalar)): (usize, (&[u8], &mut constantine::big255))| unsafe {
+            let status = constantine::ctt_bls12_381_deserialize_scalar(scalar, chunk.as_ptr());
+            (status == ctt_codec_scalar_status::cttCodecScalar_ScalarLargerThanCurveOrder)
+                .then_some(i)
+        };
+
+        #[cfg(feature = "parallel")]
+        let first_invalid = bytes
+            .par_chunks(BYTES_PER_FIELD_ELEMENT)
+            .zip(scalars.par_iter_mut())
+            .enumerate()
+            .filter_map(read)
+            .min();
+
+        #[cfg(not(feature = "parallel"))]
+        let first_invalid = bytes
+            .chunks(BYTES_PER_FIELD_ELEMENT)
+            .zip(scalars.iter_mut())
+            .enumerate()
+            .find_map(read);
+
+        if let Some(i) = first_invalid {
+            return Err(format!(
+                "Invalid field element at index {}: Invalid scalar",
+                i
+            ));
+        }
+
+        let convert = |(fr, scalar): (&mut Self, &constantine::big255)| unsafe {
+            constantine::ctt_bls12_381_fr_from_big255(&mut fr.0, scalar);
+        };
+
+        #[cfg(feature = "parallel")]
+        out.par_iter_mut().zip(scalars.par_iter()).for_each(convert);
+
+        #[cfg(not(feature = "parallel"))]
+        out.iter_mut().zip(scalars.iter()).for_each(convert);
+
+        Ok(())
+    }
+
     fn from_hex(hex: &str) -> Result<Self, String> {
         let bytes = hex::decode(&hex[2..]).unwrap();
         Self::from_bytes(&bytes)
@@ -4,6 +4,7 @@ use alloc::string::String;
 use alloc::sync::Arc;
 use alloc::vec::Vec;
 
+use kzg::common_utils::check_degree_bound;
 use kzg::msm::precompute::{precompute, PrecomputationTable};
 use kzg::{FFTFr, FFTSettings, Fr, G1Mul, G2Mul, KZGSettings, Poly, G1, G2};
 
@@ -21,8 +22,8 @@ use super::g1::CtG1Affine;
 #[derive(Clone, Default)]
 pub struct CtKZGSettings {
     pub fs: CtFFTSettings,
-    pub secret_g1: Vec<CtG1>,
-    pub secret_g2: Vec<CtG2>,
+    pub secret_g1: Arc<[CtG1]>,
+    pub secret_g2: Arc<[CtG2]>,
     pub precomputation: Option<Arc<PrecomputationTable<CtFr, CtG1, CtFp, CtG1Affine>>>,
 }
 
@@ -34,17 +35,30 @@ impl KZGSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtFp, CtG1Affine> for
         fft_settings: &CtFFTSettings,
     ) -> Result<Self, String> {
         Ok(Self {
-            secret_g1: secret_g1.to_vec(),
-            secret_g2: secret_g2.to_vec(),
+            secret_g1: secret_g1.into(),
+            secret_g2: secret_g2.into(),
             fs: fft_settings.clone(),
             precomputation: precompute(secret_g1).ok().flatten().map(Arc::new),
         })
     }
 
+    fn with_shared_points(base: &Self, with_precomputation: bool) -> Result<Self, String> {
+        let precomputation = match (&base.precomputation, with_precomputation) {
+            (_, false) => None,
+            (Some(table), true) => Some(table.clone()),
+            (None, true) => precompute(&base.secret_g1).ok().flatten().map(Arc::new),
+        };
+
+        Ok(Self {
+            fs: base.fs.clone(),
+            secret_g1: base.secret_g1.clone(),
+            secret_g2: base.secret_g2.clone(),
+            precomputation,
+        })
+    }
+
     fn commit_to_poly(&self, poly: &CtPoly) -> Result<CtG1, String> {
-        if poly.coeffs.len() > self.secret_g1.len() {
-            return Err(String::from("Polynomial is longer than secret g1"));
-        }
+        check_degree_bound(poly.coeffs.len(), self.secret_g1.len())?;
 
         let mut out = CtG1::default();
         g1_linear_combination(
@@ -63,6 +77,8 @@ impl KZGSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtFp, CtG1Affine> for
             return Err(String::from("Polynomial must not be empty"));
         }
 
+        check_degree_bound(p.coeffs.len(), self.secret_g1.len())?;
+
         // `-(x0^n)`, where `n` is `1`
         let divisor_0 = x.negate();
 
@@ -106,6 +122,8 @@ impl KZGSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtFp, CtG1Affine> for
             return Err(String::from("Polynomial must not be empty"));
         }
 
+        check_degree_bound(p.coeffs.len(), self.secret_g1.len())?;
+
         if !n.is_power_of_two() {
             return Err(String::from("n must be a power of two"));
         }
@@ -0,0 +1,30 @@
+#[cfg(test)]
+mod tests {
+    use kzg_bench::tests::consistency::{
+        das_outputs_match_reference_test, eip_4844_outputs_match_reference_test,
+    };
+    use rust_kzg_constantine::eip_4844::load_trusted_setup_filename_rust;
+    use rust_kzg_constantine::types::{
+        fft_settings::CtFFTSettings, fp::CtFp, fr::CtFr, g1::CtG1, g1::CtG1Affine, g2::CtG2,
+        kzg_settings::CtKZGSettings, poly::CtPoly,
+    };
+
+    #[test]
+    pub fn eip_4844_outputs_match_reference() {
+        eip_4844_outputs_match_reference_test::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtFFTSettings,
+            CtPoly,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >("constantine", &load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn das_outputs_match_reference() {
+        das_outputs_match_reference_test::<CtFr, CtFFTSettings, CtPoly>("constantine");
+    }
+}
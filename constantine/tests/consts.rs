@@ -5,7 +5,8 @@
 mod tests {
     use kzg_bench::tests::consts::{
         expand_roots_is_plausible, new_fft_settings_is_plausible, roots_of_unity_are_plausible,
-        roots_of_unity_is_the_expected_size, roots_of_unity_out_of_bounds_fails,
+        roots_of_unity_boundary_indices, roots_of_unity_is_the_expected_size,
+        roots_of_unity_out_of_bounds_fails,
     };
     use rust_kzg_constantine::consts::SCALE2_ROOT_OF_UNITY;
     use rust_kzg_constantine::types::fft_settings::{expand_root_of_unity, CtFFTSettings};
@@ -37,6 +38,11 @@ mod tests {
         new_fft_settings_is_plausible::<CtFr, CtFFTSettings>();
     }
 
+    #[test]
+    fn roots_of_unity_boundary_indices_() {
+        roots_of_unity_boundary_indices::<CtFr, CtFFTSettings>();
+    }
+
     // Local tests
     // #[test]
     // fn roots_of_unity_repeat_at_stride_() {
@@ -0,0 +1,41 @@
+#[cfg(all(test, feature = "counting"))]
+mod tests {
+    use kzg_bench::tests::counting::{
+        batch_challenge_op_counts, blob_flow_op_counts, counting_delegates_and_counts,
+        field_helpers_op_counts,
+    };
+    use rust_kzg_constantine::eip_4844::load_trusted_setup_filename_rust;
+    use rust_kzg_constantine::types::{
+        fft_settings::CtFFTSettings, fp::CtFp, fr::CtFr, g1::CtG1, g1::CtG1Affine, g2::CtG2,
+        kzg_settings::CtKZGSettings, poly::CtPoly,
+    };
+
+    #[test]
+    fn counting_delegates_and_counts_() {
+        counting_delegates_and_counts::<CtFr, CtG1>();
+    }
+
+    #[test]
+    fn field_helpers_op_counts_() {
+        field_helpers_op_counts::<CtFr>();
+    }
+
+    #[test]
+    fn batch_challenge_op_counts_() {
+        batch_challenge_op_counts::<CtFr, CtG1>();
+    }
+
+    #[test]
+    fn blob_flow_op_counts_() {
+        blob_flow_op_counts::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+}
@@ -8,21 +8,36 @@ mod tests {
     };
     use kzg::Fr;
 
+    #[cfg(feature = "commitment-cache")]
+    use kzg_bench::tests::eip_4844::commitment_cache_test;
+    #[cfg(feature = "ssz")]
+    use kzg_bench::tests::eip_4844::ssz_round_trip_test;
     use kzg_bench::tests::eip_4844::{
-        blob_to_kzg_commitment_test, bytes_to_bls_field_test,
+        aggregate_kzg_proof_test, blob_newtype_batch_apis_test, blob_newtype_test,
+        blob_to_kzg_commitment_batch_test, blob_to_kzg_commitment_streaming_test,
+        blob_to_kzg_commitment_test, build_blob_sidecars_test, bytes_to_bls_field_test,
         compute_and_verify_blob_kzg_proof_fails_with_incorrect_proof_test,
         compute_and_verify_blob_kzg_proof_test,
         compute_and_verify_kzg_proof_fails_with_incorrect_proof_test,
         compute_and_verify_kzg_proof_round_trip_test,
-        compute_and_verify_kzg_proof_within_domain_test, compute_kzg_proof_empty_blob_vector_test,
-        compute_kzg_proof_incorrect_blob_length_test,
+        compute_and_verify_kzg_proof_within_domain_test,
+        compute_blob_kzg_proof_incorrect_blob_length_test, compute_blob_kzg_proof_streaming_test,
+        compute_kzg_proof_empty_blob_vector_test, compute_kzg_proof_incorrect_blob_length_test,
         compute_kzg_proof_incorrect_commitments_len_test,
         compute_kzg_proof_incorrect_poly_length_test, compute_kzg_proof_incorrect_proofs_len_test,
-        compute_kzg_proof_test, compute_powers_test, test_vectors_blob_to_kzg_commitment,
-        test_vectors_compute_blob_kzg_proof, test_vectors_compute_kzg_proof,
-        test_vectors_verify_blob_kzg_proof, test_vectors_verify_blob_kzg_proof_batch,
-        test_vectors_verify_kzg_proof, validate_batched_input_test,
-        verify_kzg_proof_batch_fails_with_incorrect_proof_test, verify_kzg_proof_batch_test,
+        compute_kzg_proof_test, compute_powers_test, compute_r_powers_degenerate_challenge_test,
+        custom_domains_test, data_blob_codec_test, evaluate_blob_in_evaluation_form_test,
+        kzg_settings_dimensions_test, kzg_to_versioned_hash_test, serialize_proofs_into_test,
+        test_vectors_blob_to_kzg_commitment, test_vectors_compute_blob_kzg_proof,
+        test_vectors_compute_kzg_proof, test_vectors_verify_blob_kzg_proof,
+        test_vectors_verify_blob_kzg_proof_batch, test_vectors_verify_kzg_proof,
+        validate_batched_input_test, verify_blob_bundle_test,
+        verify_blob_kzg_proof_batch_cancellable_test,
+        verify_blob_kzg_proof_batch_single_blob_missing_commitment_test,
+        verify_identity_strict_test, verify_kzg_proof_batch_fails_with_incorrect_proof_test,
+        verify_kzg_proof_batch_same_commitment_matches_general_test,
+        verify_kzg_proof_batch_same_commitment_test, verify_kzg_proof_batch_test,
+        verify_point_evaluation_precompile_test, zero_and_constant_blob_test,
     };
     use rust_kzg_constantine::consts::SCALE2_ROOT_OF_UNITY;
     use rust_kzg_constantine::eip_4844::load_trusted_setup_filename_rust;
@@ -43,6 +58,11 @@ mod tests {
         compute_powers_test::<CtFr>(&compute_powers);
     }
 
+    #[test]
+    pub fn compute_r_powers_degenerate_challenge() {
+        compute_r_powers_degenerate_challenge_test::<CtFr, CtG1>();
+    }
+
     #[test]
     pub fn blob_to_kzg_commitment_test_() {
         blob_to_kzg_commitment_test::<
@@ -329,6 +349,20 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn kzg_settings_dimensions() {
+        kzg_settings_dimensions_test::<
+            CtPoly,
+            CtFr,
+            CtG1,
+            CtG2,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&load_trusted_setup_filename_rust)
+    }
+
     #[test]
     pub fn expand_root_of_unity_too_long() {
         let out = expand_root_of_unity(&CtFr::from_u64_arr(&SCALE2_ROOT_OF_UNITY[1]), 1);
@@ -402,6 +436,34 @@ mod tests {
         >(&verify_blob_kzg_proof_batch_rust)
     }
 
+    #[test]
+    pub fn compute_blob_kzg_proof_incorrect_blob_length() {
+        compute_blob_kzg_proof_incorrect_blob_length_test::<
+            CtPoly,
+            CtFr,
+            CtG1,
+            CtG2,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&compute_blob_kzg_proof_rust)
+    }
+
+    #[test]
+    pub fn verify_blob_kzg_proof_batch_single_blob_missing_commitment() {
+        verify_blob_kzg_proof_batch_single_blob_missing_commitment_test::<
+            CtPoly,
+            CtFr,
+            CtG1,
+            CtG2,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&verify_blob_kzg_proof_batch_rust)
+    }
+
     #[test]
     pub fn validate_batched_input() {
         validate_batched_input_test::<
@@ -418,4 +480,257 @@ mod tests {
             &load_trusted_setup_filename_rust,
         )
     }
+
+    #[test]
+    pub fn verify_identity_strict_test_() {
+        verify_identity_strict_test::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn zero_and_constant_blob_test_() {
+        zero_and_constant_blob_test::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn verify_point_evaluation_precompile_test_() {
+        verify_point_evaluation_precompile_test::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn aggregate_kzg_proof_test_() {
+        aggregate_kzg_proof_test::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn verify_kzg_proof_batch_same_commitment_test_() {
+        verify_kzg_proof_batch_same_commitment_test::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn verify_kzg_proof_batch_same_commitment_matches_general_test_() {
+        verify_kzg_proof_batch_same_commitment_matches_general_test::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn custom_domains_test_() {
+        custom_domains_test::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn blob_to_kzg_commitment_batch_test_() {
+        blob_to_kzg_commitment_batch_test::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn blob_to_kzg_commitment_streaming_test_() {
+        blob_to_kzg_commitment_streaming_test::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn compute_blob_kzg_proof_streaming_test_() {
+        compute_blob_kzg_proof_streaming_test::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn blob_newtype_test_() {
+        blob_newtype_test::<CtFr, CtG1, CtG2, CtPoly, CtFFTSettings, CtKZGSettings, CtFp, CtG1Affine>(
+            &load_trusted_setup_filename_rust,
+        );
+    }
+
+    #[test]
+    pub fn blob_newtype_batch_apis_test_() {
+        blob_newtype_batch_apis_test::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn verify_blob_bundle_test_() {
+        verify_blob_bundle_test::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn build_blob_sidecars_test_() {
+        build_blob_sidecars_test::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn evaluate_blob_in_evaluation_form_test_() {
+        evaluate_blob_in_evaluation_form_test::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn kzg_to_versioned_hash_test_() {
+        kzg_to_versioned_hash_test::<CtG1>();
+    }
+
+    #[test]
+    pub fn serialize_proofs_into_test_() {
+        serialize_proofs_into_test::<CtFr, CtG1>();
+    }
+
+    #[test]
+    #[cfg(feature = "ssz")]
+    pub fn ssz_round_trip_test_() {
+        ssz_round_trip_test::<CtFr, CtG1>();
+    }
+
+    #[test]
+    pub fn data_blob_codec_test_() {
+        data_blob_codec_test::<CtFr>();
+    }
+
+    #[test]
+    #[cfg(feature = "commitment-cache")]
+    pub fn commitment_cache_test_() {
+        commitment_cache_test::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn verify_blob_kzg_proof_batch_cancellable_test_() {
+        verify_blob_kzg_proof_batch_cancellable_test::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
 }
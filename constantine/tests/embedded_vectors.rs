@@ -0,0 +1,23 @@
+#[cfg(test)]
+mod tests {
+    use kzg_bench::tests::embedded_vectors::eip_4844_embedded_vectors_test;
+    use rust_kzg_constantine::eip_4844::load_trusted_setup_filename_rust;
+    use rust_kzg_constantine::types::{
+        fft_settings::CtFFTSettings, fp::CtFp, fr::CtFr, g1::CtG1, g1::CtG1Affine, g2::CtG2,
+        kzg_settings::CtKZGSettings, poly::CtPoly,
+    };
+
+    #[test]
+    pub fn eip_4844_embedded_vectors() {
+        eip_4844_embedded_vectors_test::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtFFTSettings,
+            CtPoly,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+}
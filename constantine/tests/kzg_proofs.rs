@@ -2,7 +2,8 @@
 mod tests {
 
     use kzg_bench::tests::kzg_proofs::{
-        commit_to_nil_poly, commit_to_too_long_poly_returns_err, proof_multi, proof_single,
+        commit_monomial_degree_bound, commit_to_nil_poly, commit_to_too_long_poly_returns_err,
+        get_g2_generator_matches_generator, proof_multi, proof_single, with_shared_points_settings,
     };
 
     use rust_kzg_constantine::types::fft_settings::CtFFTSettings;
@@ -49,10 +50,52 @@ mod tests {
         >(&generate_trusted_setup);
     }
 
+    #[test]
+    pub fn test_commit_monomial_degree_bound() {
+        commit_monomial_degree_bound::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&generate_trusted_setup);
+    }
+
     #[test]
     pub fn test_proof_multi() {
         proof_multi::<CtFr, CtG1, CtG2, CtPoly, CtFFTSettings, CtKZGSettings, CtFp, CtG1Affine>(
             &generate_trusted_setup,
         );
     }
+
+    #[test]
+    pub fn test_with_shared_points_settings() {
+        with_shared_points_settings::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&generate_trusted_setup);
+    }
+
+    #[test]
+    pub fn test_get_g2_generator_matches_generator() {
+        get_g2_generator_matches_generator::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&generate_trusted_setup);
+    }
 }
@@ -17,6 +17,11 @@ mod tests {
         recover_simple::<CtFr, CtFFTSettings, CtPoly, CtPoly>();
     }
 
+    #[test]
+    fn recover_zero_and_constant_() {
+        recover_zero_and_constant::<CtFr, CtFFTSettings, CtPoly, CtPoly>();
+    }
+
     #[test]
     fn recover_random_() {
         recover_random::<CtFr, CtFFTSettings, CtPoly, CtPoly>();
@@ -26,4 +31,20 @@ mod tests {
     fn more_than_half_missing_() {
         more_than_half_missing::<CtFr, CtFFTSettings, CtPoly, CtPoly>();
     }
+
+    #[test]
+    fn recover_concurrent_() {
+        recover_concurrent::<CtFr, CtFFTSettings, CtPoly, CtPoly>(&table_builds);
+    }
+
+    #[cfg(feature = "counting")]
+    fn table_builds() -> Vec<usize> {
+        rust_kzg_constantine::recovery::recovery_table_builds().to_vec()
+    }
+
+    // The tables are only counted with the counting feature
+    #[cfg(not(feature = "counting"))]
+    fn table_builds() -> Vec<usize> {
+        Vec::new()
+    }
 }
@@ -0,0 +1,30 @@
+#![no_main]
+
+use kzg::eip_4844::{BYTES_PER_BLOB, BYTES_PER_COMMITMENT, BYTES_PER_PROOF};
+use libfuzzer_sys::fuzz_target;
+
+// Input layout: three count bytes (blobs, commitments, proofs), followed by that many blobs,
+// commitments and proofs. The counts are kept independent so mismatched lengths get exercised.
+fuzz_target!(|data: &[u8]| {
+    if data.len() < 3 {
+        return;
+    }
+
+    let num_blobs = (data[0] % 4) as usize;
+    let num_commitments = (data[1] % 4) as usize;
+    let num_proofs = (data[2] % 4) as usize;
+
+    let blobs_len = num_blobs * BYTES_PER_BLOB;
+    let commitments_len = num_commitments * BYTES_PER_COMMITMENT;
+    let proofs_len = num_proofs * BYTES_PER_PROOF;
+
+    let data = &data[3..];
+    if data.len() != blobs_len + commitments_len + proofs_len {
+        return;
+    }
+
+    let (blobs, rest) = data.split_at(blobs_len);
+    let (commitments, proofs) = rest.split_at(commitments_len);
+
+    let _ = rust_kzg_fuzz::verify_blob_kzg_proof_batch(blobs, commitments, proofs);
+});
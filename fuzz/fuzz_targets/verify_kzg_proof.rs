@@ -0,0 +1,17 @@
+#![no_main]
+
+use kzg::eip_4844::{BYTES_PER_COMMITMENT, BYTES_PER_FIELD_ELEMENT, BYTES_PER_PROOF};
+use libfuzzer_sys::fuzz_target;
+
+// Input layout: commitment || z || y || proof
+fuzz_target!(|data: &[u8]| {
+    if data.len() != BYTES_PER_COMMITMENT + 2 * BYTES_PER_FIELD_ELEMENT + BYTES_PER_PROOF {
+        return;
+    }
+
+    let (commitment, rest) = data.split_at(BYTES_PER_COMMITMENT);
+    let (z, rest) = rest.split_at(BYTES_PER_FIELD_ELEMENT);
+    let (y, proof) = rest.split_at(BYTES_PER_FIELD_ELEMENT);
+
+    let _ = rust_kzg_fuzz::verify_kzg_proof(commitment, z, y, proof);
+});
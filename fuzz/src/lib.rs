@@ -0,0 +1,155 @@
+use std::sync::OnceLock;
+
+use kzg::eip_4844::{
+    blob_to_kzg_commitment_rust, bytes_to_blob, verify_blob_kzg_proof_batch_rust,
+    verify_kzg_proof_rust, BYTES_PER_BLOB, BYTES_PER_COMMITMENT, BYTES_PER_PROOF,
+};
+use kzg::{Fr, G1};
+
+#[cfg(not(any(
+    feature = "blst",
+    feature = "arkworks",
+    feature = "zkcrypto",
+    feature = "constantine"
+)))]
+compile_error!("enable one of the backend features: blst, arkworks, zkcrypto, constantine");
+
+#[cfg(any(
+    all(feature = "blst", feature = "arkworks"),
+    all(feature = "blst", feature = "zkcrypto"),
+    all(feature = "blst", feature = "constantine"),
+    all(feature = "arkworks", feature = "zkcrypto"),
+    all(feature = "arkworks", feature = "constantine"),
+    all(feature = "zkcrypto", feature = "constantine"),
+))]
+compile_error!(
+    "backend features are mutually exclusive, enable only one of blst, arkworks, zkcrypto or constantine (disable default features to leave out blst)"
+);
+
+#[cfg(feature = "blst")]
+mod backend {
+    pub use rust_kzg_blst::eip_4844::load_trusted_setup_filename_rust;
+    pub use rust_kzg_blst::types::{
+        fft_settings::FsFFTSettings as TFFTSettings, fp::FsFp as TG1Fp, fr::FsFr as TFr,
+        g1::FsG1 as TG1, g1::FsG1Affine as TG1Affine, g2::FsG2 as TG2,
+        kzg_settings::FsKZGSettings as TKZGSettings, poly::FsPoly as TPoly,
+    };
+}
+
+#[cfg(feature = "arkworks")]
+mod backend {
+    pub use rust_kzg_arkworks::eip_4844::load_trusted_setup_filename_rust;
+    pub use rust_kzg_arkworks::kzg_proofs::{
+        FFTSettings as TFFTSettings, KZGSettings as TKZGSettings,
+    };
+    pub use rust_kzg_arkworks::kzg_types::{
+        ArkFp as TG1Fp, ArkFr as TFr, ArkG1 as TG1, ArkG1Affine as TG1Affine, ArkG2 as TG2,
+    };
+    pub use rust_kzg_arkworks::utils::PolyData as TPoly;
+}
+
+#[cfg(feature = "zkcrypto")]
+mod backend {
+    pub use rust_kzg_zkcrypto::eip_4844::load_trusted_setup_filename_rust;
+    pub use rust_kzg_zkcrypto::kzg_proofs::{
+        FFTSettings as TFFTSettings, KZGSettings as TKZGSettings,
+    };
+    pub use rust_kzg_zkcrypto::kzg_types::{
+        ZFp as TG1Fp, ZFr as TFr, ZG1 as TG1, ZG1Affine as TG1Affine, ZG2 as TG2,
+    };
+    pub use rust_kzg_zkcrypto::poly::PolyData as TPoly;
+}
+
+#[cfg(feature = "constantine")]
+mod backend {
+    pub use rust_kzg_constantine::eip_4844::load_trusted_setup_filename_rust;
+    pub use rust_kzg_constantine::types::{
+        fft_settings::CtFFTSettings as TFFTSettings, fp::CtFp as TG1Fp, fr::CtFr as TFr,
+        g1::CtG1 as TG1, g1::CtG1Affine as TG1Affine, g2::CtG2 as TG2,
+        kzg_settings::CtKZGSettings as TKZGSettings, poly::CtPoly as TPoly,
+    };
+}
+
+use backend::*;
+
+const TRUSTED_SETUP_PATH: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../kzg-bench/src/trusted_setup.txt"
+);
+
+/// Trusted setup shared by every fuzz iteration, loaded on first use.
+pub fn settings() -> &'static TKZGSettings {
+    static SETTINGS: OnceLock<TKZGSettings> = OnceLock::new();
+    SETTINGS.get_or_init(|| {
+        load_trusted_setup_filename_rust(TRUSTED_SETUP_PATH)
+            .expect("trusted setup in kzg-bench must load")
+    })
+}
+
+pub fn blob_to_kzg_commitment(blob: &[u8]) -> Result<[u8; BYTES_PER_COMMITMENT], String> {
+    let blob = bytes_to_blob::<TFr>(blob)?;
+    let commitment = blob_to_kzg_commitment_rust::<
+        TFr,
+        TG1,
+        TG2,
+        TFFTSettings,
+        TPoly,
+        TKZGSettings,
+        TG1Fp,
+        TG1Affine,
+    >(&blob, settings())?;
+
+    Ok(commitment.to_bytes())
+}
+
+pub fn verify_kzg_proof(
+    commitment: &[u8],
+    z: &[u8],
+    y: &[u8],
+    proof: &[u8],
+) -> Result<bool, String> {
+    let commitment = TG1::from_bytes(commitment)?;
+    let z = TFr::from_bytes(z)?;
+    let y = TFr::from_bytes(y)?;
+    let proof = TG1::from_bytes(proof)?;
+
+    verify_kzg_proof_rust::<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>(
+        &commitment,
+        &z,
+        &y,
+        &proof,
+        settings(),
+    )
+}
+
+/// Verifies a batch given the concatenated encodings of its blobs, commitments and proofs.
+pub fn verify_blob_kzg_proof_batch(
+    blobs: &[u8],
+    commitments: &[u8],
+    proofs: &[u8],
+) -> Result<bool, String> {
+    let blobs = blobs
+        .chunks(BYTES_PER_BLOB)
+        .map(bytes_to_blob::<TFr>)
+        .collect::<Result<Vec<_>, _>>()?;
+    let commitments = commitments
+        .chunks(BYTES_PER_COMMITMENT)
+        .map(TG1::from_bytes)
+        .collect::<Result<Vec<_>, _>>()?;
+    let proofs = proofs
+        .chunks(BYTES_PER_PROOF)
+        .map(TG1::from_bytes)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    verify_blob_kzg_proof_batch_rust::<
+        TFr,
+        TG1,
+        TG2,
+        TFFTSettings,
+        TPoly,
+        TKZGSettings,
+        TG1Fp,
+        TG1Affine,
+        Vec<TFr>,
+    >(&blobs, &commitments, &proofs, settings())
+}
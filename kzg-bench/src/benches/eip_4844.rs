@@ -2,7 +2,10 @@ use std::env::set_current_dir;
 
 use crate::tests::eip_4844::{generate_random_blob_bytes, generate_random_field_element_bytes};
 use criterion::{BatchSize, BenchmarkId, Criterion, Throughput};
-use kzg::eip_4844::TRUSTED_SETUP_PATH;
+use kzg::eip_4844::{
+    serialize_proofs_into, BYTES_PER_BLOB, BYTES_PER_FIELD_ELEMENT, BYTES_PER_PROOF,
+    FIELD_ELEMENTS_PER_BLOB, TRUSTED_SETUP_PATH,
+};
 use kzg::{FFTSettings, Fr, G1Affine, G1Fp, G1GetFp, G1Mul, KZGSettings, Poly, G1, G2};
 
 #[allow(clippy::type_complexity)]
@@ -135,4 +138,66 @@ pub fn bench_eip_4844<
         });
     }
     group.finish();
+
+    // 512 cells of 64 field elements, as in a full batch of extended blobs
+    const PARSE_COUNT: usize = 512 * 64;
+    let fr_bytes: Vec<u8> = (0..PARSE_COUNT / FIELD_ELEMENTS_PER_BLOB)
+        .flat_map(|_| generate_random_blob_bytes(&mut rng))
+        .collect();
+    let mut group = c.benchmark_group("parse_field_elements");
+    group.throughput(Throughput::Elements(PARSE_COUNT as u64));
+    group.bench_function("from_bytes", |b| {
+        b.iter(|| {
+            fr_bytes
+                .chunks(BYTES_PER_FIELD_ELEMENT)
+                .map(TFr::from_bytes)
+                .collect::<Result<Vec<TFr>, String>>()
+                .unwrap()
+        })
+    });
+    let mut parsed = vec![TFr::default(); PARSE_COUNT];
+    group.bench_function("from_bytes_batch", |b| {
+        b.iter(|| TFr::from_bytes_batch(&fr_bytes, &mut parsed).unwrap())
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("serialize_proofs");
+    let mut out = vec![0u8; MAX_COUNT * BYTES_PER_PROOF];
+    group.throughput(Throughput::Elements(MAX_COUNT as u64));
+    group.bench_function("to_bytes", |b| {
+        b.iter(|| {
+            for (proof, chunk) in proofs.iter().zip(out.chunks_exact_mut(BYTES_PER_PROOF)) {
+                chunk.copy_from_slice(&proof.to_bytes());
+            }
+        })
+    });
+    group.bench_function("write_bytes", |b| {
+        b.iter(|| serialize_proofs_into(&proofs, &mut out).unwrap())
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("serialize_blob");
+    let mut out = vec![0u8; BYTES_PER_BLOB];
+    let blob = blobs.first().unwrap();
+    group.bench_function("to_bytes", |b| {
+        b.iter(|| {
+            for (fr, chunk) in blob
+                .iter()
+                .zip(out.chunks_exact_mut(BYTES_PER_FIELD_ELEMENT))
+            {
+                chunk.copy_from_slice(&fr.to_bytes());
+            }
+        })
+    });
+    group.bench_function("write_bytes", |b| {
+        b.iter(|| {
+            for (fr, chunk) in blob
+                .iter()
+                .zip(out.chunks_exact_mut(BYTES_PER_FIELD_ELEMENT))
+            {
+                fr.write_bytes(chunk).unwrap();
+            }
+        })
+    });
+    group.finish();
 }
@@ -65,3 +65,45 @@ pub fn bench_g1_lincomb<
         });
     }
 }
+
+#[allow(clippy::type_complexity)]
+pub fn bench_g1_lincomb_affine<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + Copy,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    c: &mut Criterion,
+    g1_linear_combination: &dyn Fn(
+        &mut TG1,
+        &[TG1],
+        &[TFr],
+        usize,
+        Option<&PrecomputationTable<TFr, TG1, TG1Fp, TG1Affine>>,
+    ),
+) {
+    for num_points in [64, 256, 1024] {
+        let points = (0..num_points).map(|_| TG1::rand()).collect::<Vec<_>>();
+        let affines = TG1Affine::into_affines(&points);
+        let scalars = (0..num_points).map(|_| TFr::rand()).collect::<Vec<_>>();
+
+        let id = format!("bench_g1_lincomb projective points: '{}'", num_points);
+        c.bench_function(&id, |b| {
+            b.iter(|| {
+                let mut out = TG1::default();
+                g1_linear_combination(
+                    &mut out,
+                    points.as_slice(),
+                    scalars.as_slice(),
+                    num_points,
+                    None,
+                )
+            })
+        });
+
+        let id = format!("bench_g1_lincomb affine points: '{}'", num_points);
+        c.bench_function(&id, |b| {
+            b.iter(|| TG1Affine::batch_scalar_mul(&affines, &scalars, num_points).unwrap())
+        });
+    }
+}
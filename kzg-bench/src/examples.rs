@@ -0,0 +1,318 @@
+use std::fs;
+use std::ptr::null_mut;
+use std::time::{Duration, Instant};
+
+use kzg::common_utils::{log2_pow2, reverse_bit_order};
+use kzg::eip_4844::{
+    bytes_to_blob, load_trusted_setup_string, Blob, Bytes32, Bytes48, CKZGSettings, KZGCommitment,
+    KZGProof, BYTES_PER_BLOB, BYTES_PER_COMMITMENT, BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1,
+    BYTES_PER_G2, BYTES_PER_PROOF, C_KZG_RET, C_KZG_RET_OK, FIELD_ELEMENTS_PER_BLOB,
+};
+use kzg::{FFTSettings, Fr, Poly, PolyRecover, DAS};
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+
+use crate::tests::eip_4844::generate_random_blob_bytes;
+
+const SAMPLE_COUNT: usize = 16;
+
+/// The byte-level C entry points of a backend, as exported from its `eip_4844` module.
+pub struct CKzgApi {
+    pub load_trusted_setup: unsafe extern "C" fn(
+        out: *mut CKZGSettings,
+        g1_bytes: *const u8,
+        n1: usize,
+        g2_bytes: *const u8,
+        n2: usize,
+    ) -> C_KZG_RET,
+    pub free_trusted_setup: unsafe extern "C" fn(s: *mut CKZGSettings),
+    pub blob_to_kzg_commitment: unsafe extern "C" fn(
+        out: *mut KZGCommitment,
+        blob: *const Blob,
+        s: &CKZGSettings,
+    ) -> C_KZG_RET,
+    pub compute_blob_kzg_proof: unsafe extern "C" fn(
+        out: *mut KZGProof,
+        blob: *const Blob,
+        commitment_bytes: *const Bytes48,
+        s: &CKZGSettings,
+    ) -> C_KZG_RET,
+    pub verify_blob_kzg_proof: unsafe extern "C" fn(
+        ok: *mut bool,
+        blob: *const Blob,
+        commitment_bytes: *const Bytes48,
+        proof_bytes: *const Bytes48,
+        s: &CKZGSettings,
+    ) -> C_KZG_RET,
+    pub compute_kzg_proof: unsafe extern "C" fn(
+        proof_out: *mut KZGProof,
+        y_out: *mut Bytes32,
+        blob: *const Blob,
+        z_bytes: *const Bytes32,
+        s: &CKZGSettings,
+    ) -> C_KZG_RET,
+    pub verify_kzg_proof: unsafe extern "C" fn(
+        ok: *mut bool,
+        commitment_bytes: *const Bytes48,
+        z_bytes: *const Bytes32,
+        y_bytes: *const Bytes32,
+        proof_bytes: *const Bytes48,
+        s: &CKZGSettings,
+    ) -> C_KZG_RET,
+}
+
+/// Time taken by each phase of [`peerdas_roundtrip`], in order.
+pub type PhaseTimings = Vec<(&'static str, Duration)>;
+
+fn timed<R>(
+    timings: &mut PhaseTimings,
+    phase: &'static str,
+    f: impl FnOnce() -> Result<R, String>,
+) -> Result<R, String> {
+    let start = Instant::now();
+    let result = f()?;
+    timings.push((phase, start.elapsed()));
+    Ok(result)
+}
+
+fn ensure(condition: bool, err: &str) -> Result<(), String> {
+    if condition {
+        Ok(())
+    } else {
+        Err(err.to_string())
+    }
+}
+
+fn check(ret: C_KZG_RET, call: &str) -> Result<(), String> {
+    if ret == C_KZG_RET_OK {
+        Ok(())
+    } else {
+        Err(format!("{} returned {}", call, ret))
+    }
+}
+
+fn commit(api: &CKzgApi, blob: &Blob, s: &CKZGSettings) -> Result<KZGCommitment, String> {
+    let mut commitment = KZGCommitment {
+        bytes: [0; BYTES_PER_COMMITMENT],
+    };
+    check(
+        unsafe { (api.blob_to_kzg_commitment)(&mut commitment, blob, s) },
+        "blob_to_kzg_commitment",
+    )?;
+    Ok(commitment)
+}
+
+fn verify_blob(
+    api: &CKzgApi,
+    blob: &Blob,
+    commitment: &Bytes48,
+    proof: &Bytes48,
+    s: &CKZGSettings,
+) -> Result<bool, String> {
+    let mut ok = false;
+    check(
+        unsafe { (api.verify_blob_kzg_proof)(&mut ok, blob, commitment, proof, s) },
+        "verify_blob_kzg_proof",
+    )?;
+    Ok(ok)
+}
+
+/// Runs a blob from producer to consumer through the backend's byte-level C API, then samples
+/// it, extends it, drops half of the extended data, recovers it and checks the commitment again.
+/// Extension and recovery have no byte-level API, so only they work on field elements parsed
+/// from the received bytes. Returns the time taken by every phase.
+pub fn peerdas_roundtrip<
+    TFr: Fr + Copy,
+    TPoly: Poly<TFr> + PolyRecover<TFr, TPoly, TFFTSettings>,
+    TFFTSettings: FFTSettings<TFr> + DAS<TFr>,
+>(
+    api: &CKzgApi,
+    trusted_setup_path: &str,
+) -> Result<PhaseTimings, String> {
+    let mut timings = PhaseTimings::new();
+
+    let mut settings = timed(&mut timings, "load trusted setup", || {
+        let contents = fs::read_to_string(trusted_setup_path).map_err(|e| e.to_string())?;
+        let (g1_bytes, g2_bytes) = load_trusted_setup_string(&contents)?;
+        let mut settings = CKZGSettings {
+            max_width: 0,
+            roots_of_unity: null_mut(),
+            g1_values: null_mut(),
+            g2_values: null_mut(),
+        };
+        check(
+            unsafe {
+                (api.load_trusted_setup)(
+                    &mut settings,
+                    g1_bytes.as_ptr(),
+                    g1_bytes.len() / BYTES_PER_G1,
+                    g2_bytes.as_ptr(),
+                    g2_bytes.len() / BYTES_PER_G2,
+                )
+            },
+            "load_trusted_setup",
+        )?;
+        Ok(settings)
+    })?;
+
+    let result = run_phases::<TFr, TPoly, TFFTSettings>(api, &settings, &mut timings);
+    unsafe { (api.free_trusted_setup)(&mut settings) };
+    result.map(|()| timings)
+}
+
+fn run_phases<
+    TFr: Fr + Copy,
+    TPoly: Poly<TFr> + PolyRecover<TFr, TPoly, TFFTSettings>,
+    TFFTSettings: FFTSettings<TFr> + DAS<TFr>,
+>(
+    api: &CKzgApi,
+    s: &CKZGSettings,
+    timings: &mut PhaseTimings,
+) -> Result<(), String> {
+    let mut rng = thread_rng();
+
+    // Producer: commit to the blob and prove it, then put everything on the wire
+    let blob = Blob {
+        bytes: generate_random_blob_bytes(&mut rng),
+    };
+    let commitment = timed(timings, "producer: blob to commitment", || {
+        commit(api, &blob, s)
+    })?;
+    let proof = timed(timings, "producer: compute blob proof", || {
+        let mut proof = KZGProof {
+            bytes: [0; BYTES_PER_PROOF],
+        };
+        let commitment = Bytes48 {
+            bytes: commitment.bytes,
+        };
+        check(
+            unsafe { (api.compute_blob_kzg_proof)(&mut proof, &blob, &commitment, s) },
+            "compute_blob_kzg_proof",
+        )?;
+        Ok(proof)
+    })?;
+    let mut message = Vec::with_capacity(BYTES_PER_COMMITMENT + BYTES_PER_PROOF + BYTES_PER_BLOB);
+    message.extend_from_slice(&commitment.bytes);
+    message.extend_from_slice(&proof.bytes);
+    message.extend_from_slice(&blob.bytes);
+
+    // Consumer: only sees the bytes that went over the network
+    let (commitment_bytes, rest) = message.split_at(BYTES_PER_COMMITMENT);
+    let (proof_bytes, blob_bytes) = rest.split_at(BYTES_PER_PROOF);
+    let received_commitment = Bytes48 {
+        bytes: commitment_bytes.try_into().unwrap(),
+    };
+    let received_proof = Bytes48 {
+        bytes: proof_bytes.try_into().unwrap(),
+    };
+    let received_blob = Blob {
+        bytes: blob_bytes.try_into().unwrap(),
+    };
+    let valid = timed(timings, "consumer: verify blob proof", || {
+        verify_blob(
+            api,
+            &received_blob,
+            &received_commitment,
+            &received_proof,
+            s,
+        )
+    })?;
+    ensure(valid, "Blob proof did not verify")?;
+
+    // Sampling: the consumer asks for openings at random points and checks each response
+    timed(timings, "sampling: open and verify points", || {
+        for _ in 0..SAMPLE_COUNT {
+            let z = Bytes32 {
+                bytes: TFr::rand().to_bytes(),
+            };
+            let mut proof = KZGProof {
+                bytes: [0; BYTES_PER_PROOF],
+            };
+            let mut y = Bytes32 { bytes: [0; 32] };
+            check(
+                unsafe { (api.compute_kzg_proof)(&mut proof, &mut y, &blob, &z, s) },
+                "compute_kzg_proof",
+            )?;
+
+            let proof = Bytes48 { bytes: proof.bytes };
+            let mut valid = false;
+            check(
+                unsafe {
+                    (api.verify_kzg_proof)(&mut valid, &received_commitment, &z, &y, &proof, s)
+                },
+                "verify_kzg_proof",
+            )?;
+            ensure(valid, "Sampled proof did not verify")?;
+        }
+        Ok(())
+    })?;
+
+    // Extension: the blob holds evaluations in bit-reversed order, the extension works on the
+    // natural order and fills in the points in between
+    let fs = TFFTSettings::new(log2_pow2(2 * FIELD_ELEMENTS_PER_BLOB))?;
+    let extended = timed(timings, "extension: das fft extension", || {
+        let mut evens: Vec<TFr> = bytes_to_blob(&received_blob.bytes)?;
+        reverse_bit_order(&mut evens)?;
+        let odds = fs.das_fft_extension(&evens)?;
+        Ok(evens
+            .into_iter()
+            .zip(odds)
+            .flat_map(|(even, odd)| [even, odd])
+            .collect::<Vec<TFr>>())
+    })?;
+
+    // Recovery: any half of the extended data is enough to get the rest back
+    let mut samples: Vec<Option<TFr>> = extended.iter().copied().map(Some).collect();
+    let mut indices: Vec<usize> = (0..samples.len()).collect();
+    indices.shuffle(&mut rng);
+    for &i in indices.iter().take(samples.len() / 2) {
+        samples[i] = None;
+    }
+    let recovered = timed(timings, "recovery: recover from half", || {
+        TPoly::recover_poly_from_samples(&samples, &fs)
+    })?;
+    ensure(
+        (0..extended.len()).all(|i| recovered.get_coeff_at(i).equals(&extended[i])),
+        "Recovered data does not match the extension",
+    )?;
+
+    let mut recovered_elements: Vec<TFr> = (0..extended.len())
+        .step_by(2)
+        .map(|i| recovered.get_coeff_at(i))
+        .collect();
+    reverse_bit_order(&mut recovered_elements)?;
+    let mut recovered_blob = Blob {
+        bytes: [0; BYTES_PER_BLOB],
+    };
+    for (element, chunk) in recovered_elements.iter().zip(
+        recovered_blob
+            .bytes
+            .chunks_exact_mut(BYTES_PER_FIELD_ELEMENT),
+    ) {
+        element.write_bytes(chunk)?;
+    }
+
+    // The recovered blob must match the commitment and proof the producer sent
+    let recovered_commitment = timed(timings, "recovery: blob to commitment", || {
+        commit(api, &recovered_blob, s)
+    })?;
+    ensure(
+        recovered_commitment.bytes == received_commitment.bytes,
+        "Recovered blob commits to a different value",
+    )?;
+    let valid = timed(timings, "recovery: verify blob proof", || {
+        verify_blob(
+            api,
+            &recovered_blob,
+            &received_commitment,
+            &received_proof,
+            s,
+        )
+    })?;
+    ensure(
+        valid,
+        "Blob proof did not verify against the recovered blob",
+    )?;
+
+    Ok(())
+}
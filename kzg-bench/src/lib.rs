@@ -1,6 +1,7 @@
 use std::env::set_current_dir;
 
 pub mod benches;
+pub mod examples;
 pub mod test_vectors;
 pub mod tests;
 
@@ -1,5 +1,7 @@
+use kzg::eip_4844::compute_powers;
 use kzg::{
-    msm::precompute::PrecomputationTable, Fr, G1Affine, G1Fp, G1GetFp, G1Mul, G2Mul, G1, G2,
+    msm::precompute::PrecomputationTable, Fr, G1Affine, G1Fp, G1GetFp, G1LinComb, G1Mul, G2Mul, G1,
+    G2,
 };
 use std::convert::TryInto;
 
@@ -113,6 +115,120 @@ pub fn fr_uint64s_roundtrip<TFr: Fr>() {
     assert_eq!(expected[3], actual[3]);
 }
 
+pub fn fr_hash_to_field_works<TFr: Fr>() {
+    // Expected values are expand_message_xmd outputs reduced modulo r
+    let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+    let expected = [
+        (
+            &b""[..],
+            "0x26cc09d2e9153c5ef3853e48a2fdd2e9d44f69112311d05825b5b9d7497ef7df",
+        ),
+        (
+            &b"abc"[..],
+            "0x3b37c01e326e740d659ca59e01d81bb4b698eba0dd166c35fde9621854c30276",
+        ),
+    ];
+
+    for (msg, hex) in expected {
+        let out = TFr::hash_to_field(msg, dst);
+        assert!(out.equals(&TFr::from_hex(hex).unwrap()));
+    }
+
+    // Oversized tags are hashed down before use
+    let out = TFr::hash_to_field(b"abc", &[b'x'; 300]);
+    let expected =
+        TFr::from_hex("0x1c25b88a793da067b25290f8e13dc05bea24a60bc09efa0c1761accee3418ceb")
+            .unwrap();
+    assert!(out.equals(&expected));
+}
+
+pub fn fr_sum_slice_and_dot_product_work<TFr: Fr>() {
+    let elems = (1..=10).map(TFr::from_u64).collect::<Vec<_>>();
+    assert!(TFr::sum_slice(&elems).equals(&TFr::from_u64(55)));
+    assert!(TFr::sum_slice(&[]).is_zero());
+
+    let a = [1, 2, 3].map(TFr::from_u64);
+    let b = [4, 5, 6].map(TFr::from_u64);
+    let dot = TFr::dot_product(&a, &b).unwrap();
+    assert!(dot.equals(&TFr::from_u64(32)));
+
+    assert!(TFr::dot_product(&a, &b[..2]).is_err());
+}
+
+pub fn fr_write_bytes_works<TFr: Fr>() {
+    let frs = [TFr::zero(), TFr::one(), TFr::from_u64(0x4844).negate()];
+    let mut out = [0u8; 3 * 32];
+    for (fr, chunk) in frs.iter().zip(out.chunks_exact_mut(32)) {
+        fr.write_bytes(chunk).unwrap();
+    }
+    for (fr, chunk) in frs.iter().zip(out.chunks_exact(32)) {
+        assert_eq!(chunk, fr.to_bytes());
+    }
+
+    assert!(frs[0].write_bytes(&mut [0u8; 31]).is_err());
+    assert!(frs[0].write_bytes(&mut [0u8; 33]).is_err());
+}
+
+pub fn fr_from_bytes_batch_works<TFr: Fr>() {
+    const N: usize = 64;
+
+    let frs: Vec<TFr> = (0..N).map(|i| TFr::from_u64(i as u64 * 0x4844)).collect();
+    let bytes: Vec<u8> = frs.iter().flat_map(|fr| fr.to_bytes()).collect();
+
+    let mut out = vec![TFr::default(); N];
+    TFr::from_bytes_batch(&bytes, &mut out).unwrap();
+    for (parsed, fr) in out.iter().zip(frs.iter()) {
+        assert!(parsed.equals(fr));
+    }
+
+    assert!(TFr::from_bytes_batch(&bytes[1..], &mut out).is_err());
+    assert!(TFr::from_bytes_batch(&bytes, &mut out[1..]).is_err());
+
+    // All ones is above the modulus; the error must name the first such element
+    for bad in [0, N / 2, N - 1] {
+        let mut bytes = bytes.clone();
+        bytes[bad * 32..(bad + 1) * 32].fill(0xff);
+        bytes[(N - 1) * 32..].fill(0xff);
+
+        let err = TFr::from_bytes_batch(&bytes, &mut out).unwrap_err();
+        assert!(
+            err.starts_with(&format!("Invalid field element at index {}:", bad)),
+            "{}",
+            err
+        );
+    }
+}
+
+/// The batch verifier computes \sum r^i (C_i - [y_i]) as \sum r^i C_i - [\sum r^i y_i]. Both
+/// forms must give the same point on the same inputs.
+pub fn g1_lincomb_c_minus_y_forms_agree<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>() {
+    for n in [1, 2, 16] {
+        let commitments = (0..n)
+            .map(|_| TG1::generator().mul(&TFr::rand()))
+            .collect::<Vec<_>>();
+        let ys = (0..n).map(|_| TFr::rand()).collect::<Vec<_>>();
+        let r_powers = compute_powers(&TFr::rand(), n);
+
+        let c_minus_y = commitments
+            .iter()
+            .zip(&ys)
+            .map(|(c, y)| c.sub(&TG1::generator().mul(y)))
+            .collect::<Vec<_>>();
+        let per_proof = TG1::g1_lincomb(&c_minus_y, &r_powers, n, None);
+
+        let r_times_y = TFr::dot_product(&r_powers, &ys).unwrap();
+        let folded = TG1::g1_lincomb(&commitments, &r_powers, n, None)
+            .sub(&TG1::generator().mul(&r_times_y));
+
+        assert!(per_proof.equals(&folded));
+    }
+}
+
 pub fn p1_mul_works<TFr: Fr, TG1: G1 + G1Mul<TFr>>() {
     let m1: [u64; 4] = [
         0xffffffff00000000,
@@ -131,6 +247,24 @@ pub fn p1_sub_works<TG1: G1>() {
     assert!(tmp.equals(&res));
 }
 
+pub fn g1_write_bytes_works<TFr: Fr, TG1: G1 + G1Mul<TFr>>() {
+    let points = [
+        TG1::identity(),
+        TG1::generator(),
+        TG1::generator().mul(&TFr::from_u64(0x4844)),
+    ];
+    let mut out = [0u8; 3 * 48];
+    for (point, chunk) in points.iter().zip(out.chunks_exact_mut(48)) {
+        point.write_bytes(chunk).unwrap();
+    }
+    for (point, chunk) in points.iter().zip(out.chunks_exact(48)) {
+        assert_eq!(chunk, point.to_bytes());
+    }
+
+    assert!(points[0].write_bytes(&mut [0u8; 47]).is_err());
+    assert!(points[0].write_bytes(&mut [0u8; 49]).is_err());
+}
+
 pub fn p2_add_or_dbl_works<TG2: G2>() {
     let expected = TG2::generator().dbl();
     let actual = TG2::generator().add_or_dbl(&TG2::generator());
@@ -237,6 +371,54 @@ pub fn g1_random_linear_combination<
     assert!(exp.equals(&res));
 }
 
+pub fn g1_affine_batch_scalar_mul<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + Copy,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>() {
+    for len in [1, 7, 8, 300] {
+        let mut coeffs = vec![TFr::default(); len];
+        let mut p = vec![TG1::default(); len];
+        let mut p1tmp = TG1::generator();
+
+        for i in 0..len {
+            coeffs[i] = TFr::rand();
+            p[i] = p1tmp;
+            p1tmp = p1tmp.dbl();
+        }
+
+        let mut exp = TG1::identity();
+        for i in 0..len {
+            p1tmp = p[i].mul(&coeffs[i]);
+            exp = exp.add_or_dbl(&p1tmp);
+        }
+
+        let affines = TG1Affine::into_affines(&p);
+        let res = TG1Affine::batch_scalar_mul(&affines, &coeffs, len).unwrap();
+
+        assert!(exp.equals(&res));
+    }
+}
+
+/// Check that asking for more points or scalars than given returns an error instead of reading
+/// past the slices
+pub fn g1_affine_batch_scalar_mul_short_points<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + Copy,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>() {
+    for len in [4, 16] {
+        let p = vec![TG1::generator(); len];
+        let affines = TG1Affine::into_affines(&p);
+        let coeffs = vec![TFr::rand(); len + 1];
+
+        assert!(TG1Affine::batch_scalar_mul(&affines, &coeffs, len + 1).is_err());
+        assert!(TG1Affine::batch_scalar_mul(&affines, &coeffs[..len - 1], len).is_err());
+    }
+}
+
 pub fn pairings_work<TFr: Fr, TG1: G1 + G1Mul<TFr>, TG2: G2 + G2Mul<TFr>>(
     pairings_verify: &dyn Fn(&TG1, &TG2, &TG1, &TG2) -> bool,
 ) {
@@ -0,0 +1,354 @@
+use crate::tests::utils::{get_manifest_dir, get_trusted_setup_path};
+use kzg::eip_4844::{
+    blob_to_kzg_commitment_rust, bytes_to_blob, compute_blob_kzg_proof_rust,
+    compute_kzg_proof_rust, hash, verify_blob_kzg_proof_batch_rust, verify_blob_kzg_proof_rust,
+    verify_kzg_proof_rust, BYTES_PER_BLOB, BYTES_PER_FIELD_ELEMENT, FIELD_ELEMENTS_PER_BLOB,
+};
+use kzg::{
+    FFTFr, FFTSettings, Fr, G1Affine, G1Fp, G1GetFp, G1LinComb, G1Mul, KZGSettings, PairingVerify,
+    Poly, PolyRecover, DAS, G1, G2,
+};
+
+use std::fmt::Write;
+use std::fs;
+use std::path::PathBuf;
+
+const NUM_BLOBS: usize = 2;
+const SEEDS: [u64; 2] = [0, 0x4844];
+const EIP_4844_REFERENCE: &str = "src/tests/fixtures/consistency/eip_4844.txt";
+const DAS_SCALE: usize = 8;
+const DAS_REFERENCE: &str = "src/tests/fixtures/consistency/das.txt";
+
+/// Labeled outputs of one backend, in the order they were produced.
+type Outputs = Vec<(String, Vec<u8>)>;
+
+fn record<T>(
+    out: &mut Outputs,
+    label: String,
+    value: Result<T, String>,
+    to_bytes: fn(&T) -> Vec<u8>,
+) {
+    let bytes = match value {
+        Ok(value) => to_bytes(&value),
+        Err(_) => b"err".to_vec(),
+    };
+    out.push((label, bytes));
+}
+
+/// Canonical field element bytes derived from `seed` and `i`.
+fn field_element_bytes(seed: u64, i: usize) -> [u8; BYTES_PER_FIELD_ELEMENT] {
+    let mut input = [0u8; 16];
+    input[..8].copy_from_slice(&seed.to_be_bytes());
+    input[8..].copy_from_slice(&(i as u64).to_be_bytes());
+
+    let mut bytes = hash(&input);
+    // Clearing the top byte keeps the value below the modulus
+    bytes[0] = 0;
+    bytes
+}
+
+fn blob_bytes(seed: u64) -> Vec<u8> {
+    (0..FIELD_ELEMENTS_PER_BLOB)
+        .flat_map(|i| field_element_bytes(seed, i))
+        .collect()
+}
+
+#[allow(clippy::type_complexity)]
+fn run_eip_4844<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine> + PairingVerify<TG1, TG2>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine> + Sync,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    ts: &TKZGSettings,
+    seed: u64,
+    out: &mut Outputs,
+) {
+    let fr_bytes = |fr: &TFr| fr.to_bytes().to_vec();
+    let g1_bytes = |g1: &TG1| g1.to_bytes().to_vec();
+    let bool_bytes = |ok: &bool| vec![*ok as u8];
+
+    let mut blobs = Vec::new();
+    let mut commitments = Vec::new();
+    let mut proofs = Vec::new();
+
+    for b in 0..NUM_BLOBS {
+        let blob = bytes_to_blob::<TFr>(&blob_bytes(seed + b as u64)).unwrap();
+
+        let commitment = blob_to_kzg_commitment_rust(&blob, ts);
+        record(
+            out,
+            format!("seed {seed} blob {b} commitment"),
+            commitment.clone(),
+            g1_bytes,
+        );
+        let commitment = commitment.unwrap();
+
+        let z = TFr::from_bytes(&field_element_bytes(!seed, b)).unwrap();
+        let proof_and_y = compute_kzg_proof_rust(&blob, &z, ts);
+        record(
+            out,
+            format!("seed {seed} blob {b} kzg proof"),
+            proof_and_y.clone(),
+            |(proof, _)| proof.to_bytes().to_vec(),
+        );
+        record(
+            out,
+            format!("seed {seed} blob {b} kzg proof y"),
+            proof_and_y.clone(),
+            |(_, y)| y.to_bytes().to_vec(),
+        );
+        let (proof, y) = proof_and_y.unwrap();
+
+        let valid = verify_kzg_proof_rust(&commitment, &z, &y, &proof, ts);
+        record(
+            out,
+            format!("seed {seed} blob {b} verify kzg proof"),
+            valid,
+            bool_bytes,
+        );
+
+        let wrong_y = y.add(&TFr::one());
+        let valid = verify_kzg_proof_rust(&commitment, &z, &wrong_y, &proof, ts);
+        record(
+            out,
+            format!("seed {seed} blob {b} verify kzg proof, wrong y"),
+            valid,
+            bool_bytes,
+        );
+
+        let blob_proof = compute_blob_kzg_proof_rust(&blob, &commitment, ts);
+        record(
+            out,
+            format!("seed {seed} blob {b} blob proof"),
+            blob_proof.clone(),
+            g1_bytes,
+        );
+        let blob_proof = blob_proof.unwrap();
+
+        let valid = verify_blob_kzg_proof_rust(&blob, &commitment, &blob_proof, ts);
+        record(
+            out,
+            format!("seed {seed} blob {b} verify blob proof"),
+            valid,
+            bool_bytes,
+        );
+
+        blobs.push(blob);
+        commitments.push(commitment);
+        proofs.push(blob_proof);
+    }
+
+    let valid = verify_blob_kzg_proof_batch_rust(&blobs, &commitments, &proofs, ts);
+    record(out, format!("seed {seed} verify batch"), valid, bool_bytes);
+
+    let mut swapped_proofs = proofs.clone();
+    swapped_proofs.reverse();
+    let valid = verify_blob_kzg_proof_batch_rust(&blobs, &commitments, &swapped_proofs, ts);
+    record(
+        out,
+        format!("seed {seed} verify batch, swapped proofs"),
+        valid,
+        bool_bytes,
+    );
+
+    let valid = verify_blob_kzg_proof_batch_rust(&blobs, &commitments[..1], &proofs, ts);
+    record(
+        out,
+        format!("seed {seed} verify batch, missing commitment"),
+        valid,
+        bool_bytes,
+    );
+
+    // Malformed inputs
+    let mut non_canonical = blob_bytes(seed);
+    non_canonical[..BYTES_PER_FIELD_ELEMENT].fill(0xff);
+    let blob = bytes_to_blob::<TFr>(&non_canonical);
+    record(out, format!("seed {seed} non-canonical blob"), blob, |_| {
+        b"ok".to_vec()
+    });
+
+    let blob = bytes_to_blob::<TFr>(&non_canonical[..BYTES_PER_BLOB - 1]);
+    record(out, format!("seed {seed} short blob"), blob, |_| {
+        b"ok".to_vec()
+    });
+
+    // Depending on the seed this lands off the curve or outside the subgroup. Backends differ in
+    // which of those `from_bytes` itself rejects, so compare at the verification boundary.
+    let mut corrupted = commitments[0].to_bytes();
+    corrupted[47] ^= 1;
+    let valid = TG1::from_bytes(&corrupted)
+        .and_then(|commitment| verify_blob_kzg_proof_rust(&blobs[0], &commitment, &proofs[0], ts));
+    record(
+        out,
+        format!("seed {seed} verify blob proof, corrupted commitment"),
+        valid,
+        bool_bytes,
+    );
+
+    let fr = TFr::from_bytes(&[0xff; BYTES_PER_FIELD_ELEMENT]);
+    record(
+        out,
+        format!("seed {seed} non-canonical field element"),
+        fr,
+        fr_bytes,
+    );
+}
+
+fn run_das<
+    TFr: Fr,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr> + DAS<TFr>,
+    TPoly: Poly<TFr> + PolyRecover<TFr, TPoly, TFFTSettings>,
+>(
+    fs: &TFFTSettings,
+    seed: u64,
+    out: &mut Outputs,
+) {
+    let frs_bytes = |frs: &Vec<TFr>| frs.iter().flat_map(|fr| fr.to_bytes()).collect();
+    let poly_bytes = |poly: &TPoly| {
+        (0..poly.len())
+            .flat_map(|i| poly.get_coeff_at(i).to_bytes())
+            .collect()
+    };
+
+    let width = fs.get_max_width();
+    let evens = (0..width / 2)
+        .map(|i| TFr::from_bytes(&field_element_bytes(seed, i)).unwrap())
+        .collect::<Vec<_>>();
+
+    let odds = fs.das_fft_extension(&evens);
+    record(out, format!("seed {seed} das extension"), odds, frs_bytes);
+
+    let odds = fs.das_fft_extension(&evens[1..]);
+    record(
+        out,
+        format!("seed {seed} das extension, odd length"),
+        odds,
+        frs_bytes,
+    );
+
+    // Low-degree data: the evens as coefficients, padded with zeros to the full width
+    let mut coeffs = evens;
+    coeffs.resize(width, TFr::zero());
+    let data = fs.fft_fr(&coeffs, false);
+    record(out, format!("seed {seed} fft"), data.clone(), frs_bytes);
+    let data = data.unwrap();
+
+    // Drops exactly half of the samples, in runs of two shifted by the seed
+    let mut samples = data
+        .iter()
+        .enumerate()
+        .map(|(i, fr)| ((i + seed as usize) % 4 >= 2).then(|| fr.clone()))
+        .collect::<Vec<_>>();
+    let recovered = TPoly::recover_poly_from_samples(&samples, fs);
+    record(out, format!("seed {seed} recovery"), recovered, poly_bytes);
+
+    let first = samples.iter().position(Option::is_some).unwrap();
+    samples[first] = None;
+    let recovered = TPoly::recover_poly_from_samples(&samples, fs);
+    record(
+        out,
+        format!("seed {seed} recovery, too many missing"),
+        recovered,
+        poly_bytes,
+    );
+}
+
+/// Compares `outputs` with the reference file at `reference`, relative to the manifest directory.
+///
+/// Setting `UPDATE_CONSISTENCY_REFERENCE` rewrites the reference from `outputs` instead.
+fn check_reference(reference: &str, backend: &str, outputs: &Outputs) {
+    let path = PathBuf::from(get_manifest_dir()).join(reference);
+
+    if std::env::var_os("UPDATE_CONSISTENCY_REFERENCE").is_some() {
+        let contents = outputs.iter().fold(String::new(), |mut s, (label, bytes)| {
+            writeln!(s, "{label}\t{}", hex::encode(bytes)).unwrap();
+            s
+        });
+        fs::write(path, contents).unwrap();
+        return;
+    }
+
+    let reference = fs::read_to_string(path).unwrap();
+    let reference = reference
+        .lines()
+        .map(|line| {
+            let (label, bytes) = line.split_once('\t').unwrap();
+            (label.to_string(), hex::decode(bytes).unwrap())
+        })
+        .collect::<Outputs>();
+
+    assert_same_outputs(("reference", &reference), (backend, outputs));
+}
+
+/// Runs the EIP-4844 flows on fixed seeds and compares every output with the reference file.
+/// Backends can't be linked into one binary, so each one checks against the shared file instead.
+#[allow(clippy::type_complexity)]
+pub fn eip_4844_outputs_match_reference_test<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine> + PairingVerify<TG1, TG2>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine> + Sync,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    backend: &str,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+
+    let mut outputs = Outputs::new();
+    for seed in SEEDS {
+        run_eip_4844(&ts, seed, &mut outputs);
+    }
+
+    check_reference(EIP_4844_REFERENCE, backend, &outputs);
+}
+
+/// Runs the DAS extension and recovery flows on fixed seeds and compares every output with the
+/// reference file, like [`eip_4844_outputs_match_reference_test`].
+pub fn das_outputs_match_reference_test<
+    TFr: Fr,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr> + DAS<TFr>,
+    TPoly: Poly<TFr> + PolyRecover<TFr, TPoly, TFFTSettings>,
+>(
+    backend: &str,
+) {
+    let fs = TFFTSettings::new(DAS_SCALE).unwrap();
+
+    let mut outputs = Outputs::new();
+    for seed in SEEDS {
+        run_das::<TFr, TFFTSettings, TPoly>(&fs, seed, &mut outputs);
+    }
+
+    check_reference(DAS_REFERENCE, backend, &outputs);
+}
+
+/// Compares two sets of outputs, naming both sides and the first output that differs.
+fn assert_same_outputs(a: (&str, &Outputs), b: (&str, &Outputs)) {
+    let ((name_a, out_a), (name_b, out_b)) = (a, b);
+
+    for ((label_a, bytes_a), (label_b, bytes_b)) in out_a.iter().zip(out_b) {
+        assert_eq!(
+            label_a, label_b,
+            "{name_a} and {name_b} ran different flows"
+        );
+        assert!(
+            bytes_a == bytes_b,
+            "{name_a} and {name_b} differ at '{label_a}': {} vs {}",
+            hex::encode(bytes_a),
+            hex::encode(bytes_b)
+        );
+    }
+    assert_eq!(
+        out_a.len(),
+        out_b.len(),
+        "{name_a} and {name_b} produced a different number of outputs"
+    );
+}
@@ -58,3 +58,33 @@ pub fn new_fft_settings_is_plausible<TFr: Fr, TFFTSettings: FFTSettings<TFr>>()
         assert!(prod.is_one());
     }
 }
+
+/// Check the roots at the edges of the domain, including the wrapped index equal to the width.
+pub fn roots_of_unity_boundary_indices<TFr: Fr, TFFTSettings: FFTSettings<TFr>>() {
+    let scale = 4;
+    let width: usize = 1 << scale;
+    let fft_settings = TFFTSettings::new(scale).unwrap();
+
+    assert!(fft_settings.get_expanded_roots_of_unity_at(0).is_one());
+    assert!(fft_settings.get_expanded_roots_of_unity_at(width).is_one());
+    assert!(fft_settings.get_reverse_roots_of_unity_at(0).is_one());
+    assert!(fft_settings.get_reverse_roots_of_unity_at(width).is_one());
+
+    // Bit reversal puts the root of order 2 right after 1
+    let minus_one = TFr::one().negate();
+    assert!(fft_settings.get_roots_of_unity_at(0).is_one());
+    assert!(fft_settings.get_roots_of_unity_at(1).equals(&minus_one));
+    assert!(fft_settings
+        .get_roots_of_unity_at(width - 1)
+        .equals(&fft_settings.get_expanded_roots_of_unity_at(width - 1)));
+    assert!(fft_settings.get_roots_of_unity_at(width).is_one());
+
+    for i in 0..=width {
+        assert!(fft_settings
+            .try_get_roots_of_unity_at(i)
+            .unwrap()
+            .equals(&fft_settings.get_roots_of_unity_at(i)));
+    }
+    assert!(fft_settings.try_get_roots_of_unity_at(width + 1).is_err());
+    assert!(fft_settings.try_get_roots_of_unity_at(usize::MAX).is_err());
+}
@@ -0,0 +1,185 @@
+use crate::tests::eip_4844::generate_random_blob_bytes;
+use crate::tests::utils::get_trusted_setup_path;
+use kzg::counting::{count_ops, Counting, OpCounts};
+use kzg::digest::DefaultDigest;
+use kzg::eip_4844::{
+    blob_to_kzg_commitment_rust, bytes_to_blob, compute_blob_kzg_proof_rust, compute_powers,
+    compute_r_powers_with_digest, verify_blob_kzg_proof_batch_rust, FIELD_ELEMENTS_PER_BLOB,
+};
+use kzg::{
+    FFTSettings, Fr, G1Affine, G1Fp, G1GetFp, G1LinComb, G1Mul, KZGSettings, PairingVerify, Poly,
+    G1, G2,
+};
+
+pub fn counting_delegates_and_counts<TFr: Fr, TG1: G1 + G1Mul<TFr>>() {
+    let a = TFr::from_u64(3);
+    let b = TFr::from_u64(5);
+
+    let (product, counts) = count_ops(|| Counting(a.clone()).mul(&Counting(b.clone())));
+    assert!(product.0.equals(&a.mul(&b)));
+    assert_eq!(
+        counts,
+        OpCounts {
+            fr_mul: 1,
+            ..Default::default()
+        }
+    );
+
+    let ((quotient, difference), counts) = count_ops(|| {
+        let (a, b) = (Counting(a.clone()), Counting(b.clone()));
+        (a.div(&b).unwrap(), a.sub(&b))
+    });
+    assert!(quotient.0.equals(&a.div(&b).unwrap()));
+    assert!(difference.0.equals(&a.sub(&b)));
+    assert_eq!(
+        counts,
+        OpCounts {
+            fr_add: 1,
+            fr_inverse: 1,
+            ..Default::default()
+        }
+    );
+
+    let g = TG1::generator();
+    let ((sum, doubled, scaled), counts) = count_ops(|| {
+        let g = Counting(g.clone());
+        (
+            g.add_or_dbl(&g),
+            g.dbl(),
+            g.mul(&Counting(TFr::from_u64(2))),
+        )
+    });
+    assert!(sum.0.equals(&g.dbl()));
+    assert!(doubled.0.equals(&g.dbl()));
+    assert!(scaled.0.equals(&g.dbl()));
+    assert_eq!(
+        counts,
+        OpCounts {
+            g1_add: 1,
+            g1_dbl: 1,
+            g1_mul: 1,
+            ..Default::default()
+        }
+    );
+
+    // Nested measurements both see the inner operations
+    let (_, outer) = count_ops(|| {
+        let (_, inner) = count_ops(|| Counting(a.clone()).sqr());
+        assert_eq!(inner.fr_sqr, 1);
+        Counting(a.clone()).sqr()
+    });
+    assert_eq!(outer.fr_sqr, 2);
+}
+
+pub fn field_helpers_op_counts<TFr: Fr>() {
+    let n = 256;
+    let a: Vec<Counting<TFr>> = (0..n).map(|i| Counting(TFr::from_u64(i + 1))).collect();
+    let b: Vec<Counting<TFr>> = (0..n).map(|i| Counting(TFr::from_u64(2 * i + 3))).collect();
+
+    let (powers, counts) = count_ops(|| compute_powers(&a[1], n as usize));
+    assert_eq!(powers.len(), n as usize);
+    assert!(counts.fr_mul < n);
+    assert_eq!(counts.fr_inverse, 0);
+
+    let (_, counts) = count_ops(|| Counting::<TFr>::sum_slice(&a));
+    assert!(counts.fr_add <= n);
+    assert_eq!(counts.fr_mul, 0);
+
+    let (_, counts) = count_ops(|| Counting::<TFr>::dot_product(&a, &b).unwrap());
+    assert!(counts.fr_mul <= n);
+    assert!(counts.fr_add <= n);
+
+    // One reduction step per 64-bit limb of the 512-bit uniform string
+    let (_, counts) = count_ops(|| Counting::<TFr>::hash_to_field(b"counting", b"DST"));
+    assert!(counts.fr_mul <= 8);
+    assert!(counts.fr_add <= 8);
+    assert_eq!(counts.fr_inverse, 0);
+}
+
+pub fn batch_challenge_op_counts<TFr: Fr, TG1: G1>() {
+    let n = 64;
+    let commitments = vec![Counting(TG1::generator()); n];
+    let proofs = vec![Counting(TG1::negative_generator()); n];
+    let zs: Vec<Counting<TFr>> = (0..n).map(|i| Counting(TFr::from_u64(i as u64))).collect();
+    let ys: Vec<Counting<TFr>> = (0..n)
+        .map(|i| Counting(TFr::from_u64(i as u64 + 1)))
+        .collect();
+
+    // The verifier derives r^0..r^(n - 1) from the transcript without any group arithmetic
+    let (powers, counts) = count_ops(|| {
+        compute_r_powers_with_digest::<Counting<TG1>, Counting<TFr>, DefaultDigest>(
+            &commitments,
+            &zs,
+            &ys,
+            &proofs,
+            false,
+        )
+        .unwrap()
+    });
+    assert_eq!(powers.len(), n);
+    assert!(counts.fr_mul < n as u64);
+    assert_eq!(counts.fr_inverse, 0);
+    assert_eq!(counts.g1_add + counts.g1_dbl + counts.g1_mul, 0);
+}
+
+/// Runs the blob flows on wrapped settings and bounds the work each one does
+#[allow(clippy::type_complexity)]
+pub fn blob_flow_op_counts<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine> + PairingVerify<TG1, TG2>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine> + Sync,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
+) {
+    let ts = Counting(load_trusted_setup(get_trusted_setup_path().as_str()).unwrap());
+    let n = FIELD_ELEMENTS_PER_BLOB as u64;
+    let num_blobs = 2;
+
+    let mut rng = rand::thread_rng();
+    let blobs: Vec<Vec<Counting<TFr>>> = (0..num_blobs)
+        .map(|_| bytes_to_blob(&generate_random_blob_bytes(&mut rng)).unwrap())
+        .collect();
+
+    // A commitment is a single MSM over the blob, with no field arithmetic
+    let (commitments, counts) = count_ops(|| {
+        blobs
+            .iter()
+            .map(|blob| blob_to_kzg_commitment_rust(blob, &ts).unwrap())
+            .collect::<Vec<_>>()
+    });
+    assert_eq!(counts.g1_lincomb, num_blobs);
+    assert_eq!(counts.g1_lincomb_points, num_blobs * n);
+    assert_eq!(counts.fr_mul + counts.fr_inverse, 0);
+
+    // Evaluating the blob twice and building the quotient each take one batch inversion
+    let (proof, counts) =
+        count_ops(|| compute_blob_kzg_proof_rust(&blobs[0], &commitments[0], &ts).unwrap());
+    assert_eq!(counts.g1_lincomb, 1);
+    assert_eq!(counts.g1_lincomb_points, n);
+    assert!(counts.fr_inverse <= 3);
+    assert!(counts.fr_mul <= 10 * n);
+    assert_eq!(counts.pairing_checks + counts.fft, 0);
+
+    let proofs = vec![
+        proof,
+        compute_blob_kzg_proof_rust(&blobs[1], &commitments[1], &ts).unwrap(),
+    ];
+
+    // The batch evaluates every blob once and then checks all proofs with three MSMs over
+    // `num_blobs` points and one pairing check. With the `parallel` feature part of this runs on
+    // other threads and isn't counted, which only lowers the counts.
+    let (valid, counts) =
+        count_ops(|| verify_blob_kzg_proof_batch_rust(&blobs, &commitments, &proofs, &ts));
+    assert!(valid.unwrap());
+    assert!(counts.g1_lincomb <= 3);
+    assert!(counts.g1_lincomb_points <= 3 * num_blobs);
+    assert!(counts.pairing_checks <= 1);
+    assert!(counts.fr_inverse <= 2 * num_blobs);
+    assert!(counts.fr_mul <= 6 * n * num_blobs);
+    assert_eq!(counts.fft, 0);
+}
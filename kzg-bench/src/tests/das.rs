@@ -1,3 +1,8 @@
+#[cfg(feature = "tokio")]
+use std::sync::Arc;
+
+#[cfg(feature = "tokio")]
+use kzg::das_async::{AsyncDAS, BlockingPool, ThreadPerJob, TokioPool};
 use kzg::{FFTFr, FFTSettings, Fr, DAS};
 
 /// Check if DAS FFT creates odds that match precomputed values
@@ -67,3 +72,101 @@ pub fn das_extension_test_random<
         }
     }
 }
+
+/// Check that settings built from an explicitly supplied root match the default ones
+pub fn das_extension_with_explicit_root<
+    TFr: Fr,
+    TFFTSettings: FFTSettings<TFr> + DAS<TFr> + FFTFr<TFr>,
+>() {
+    let scale: usize = 6;
+
+    let fft_settings = TFFTSettings::new(scale).unwrap();
+    let root_of_unity = fft_settings.get_expanded_roots_of_unity_at(1);
+    let explicit_settings = TFFTSettings::new_with_root(scale, &root_of_unity).unwrap();
+
+    assert_eq!(
+        fft_settings.get_max_width(),
+        explicit_settings.get_max_width()
+    );
+    for (a, b) in fft_settings
+        .get_roots_of_unity()
+        .iter()
+        .zip(explicit_settings.get_roots_of_unity())
+    {
+        assert!(a.equals(b));
+    }
+
+    let evens = (0..fft_settings.get_max_width() / 2)
+        .map(|_| TFr::rand())
+        .collect::<Vec<_>>();
+    let odds = fft_settings.das_fft_extension(&evens).unwrap();
+    let explicit_odds = explicit_settings.das_fft_extension(&evens).unwrap();
+
+    assert_eq!(odds.len(), explicit_odds.len());
+    for (a, b) in odds.iter().zip(explicit_odds.iter()) {
+        assert_eq!(a.to_bytes(), b.to_bytes());
+    }
+}
+
+/// Check that roots whose order is not exactly 2^scale are rejected
+pub fn fft_settings_new_with_root_wrong_order<TFr: Fr, TFFTSettings: FFTSettings<TFr>>() {
+    let scale: usize = 6;
+
+    let larger_settings = TFFTSettings::new(scale + 1).unwrap();
+
+    // Order 2^(scale + 1)
+    let too_large = larger_settings.get_expanded_roots_of_unity_at(1);
+    assert!(TFFTSettings::new_with_root(scale, &too_large).is_err());
+
+    // Order 2^(scale - 1)
+    let too_small = larger_settings.get_expanded_roots_of_unity_at(4);
+    assert!(TFFTSettings::new_with_root(scale, &too_small).is_err());
+
+    // Not a root of unity of any power of two order
+    assert!(TFFTSettings::new_with_root(scale, &TFr::from_u64(2)).is_err());
+
+    assert!(TFFTSettings::new_with_root(64, &too_large).is_err());
+}
+
+/// Check that the async extension gives the same odds and errors as the sync one
+#[cfg(feature = "tokio")]
+pub fn das_extension_async_matches_sync<
+    TFr: Fr + 'static,
+    TFFTSettings: FFTSettings<TFr> + DAS<TFr> + Send + Sync + 'static,
+>() {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let fft_settings = Arc::new(TFFTSettings::new(6).unwrap());
+    let inputs: Vec<Vec<TFr>> = (0..4)
+        .map(|rep| (0..16).map(|i| TFr::from_u64(rep * 100 + i)).collect())
+        .collect();
+
+    let pools: [&dyn BlockingPool; 2] = [&ThreadPerJob, &TokioPool];
+    for pool in pools {
+        runtime.block_on(async {
+            // Several tasks in flight at once each resolve to their own result
+            let tasks: Vec<_> = inputs
+                .iter()
+                .map(|evens| {
+                    fft_settings
+                        .clone()
+                        .das_fft_extension_async(evens.clone(), pool)
+                })
+                .collect();
+
+            for (evens, task) in inputs.iter().zip(tasks) {
+                let odds = task.await.unwrap();
+                let expected = fft_settings.das_fft_extension(evens).unwrap();
+                assert_eq!(odds.len(), expected.len());
+                assert!(odds.iter().zip(&expected).all(|(a, b)| a.equals(b)));
+            }
+
+            let err = fft_settings
+                .clone()
+                .das_fft_extension_async(Vec::new(), pool)
+                .await;
+            assert_eq!(err.err(), fft_settings.das_fft_extension(&[]).err());
+        });
+    }
+}
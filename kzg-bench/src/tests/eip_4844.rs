@@ -5,17 +5,54 @@ use crate::test_vectors::{
     verify_blob_kzg_proof_batch, verify_kzg_proof,
 };
 use crate::tests::utils::{get_manifest_dir, get_trusted_setup_path};
+use kzg::blob::Blob;
+#[cfg(feature = "commitment-cache")]
+use kzg::commitment_cache::{verify_blob_kzg_proof_cached_rust, CommitmentCache};
+use kzg::digest::{Digest, Sha2Digest};
 use kzg::eip_4844::{
-    BYTES_PER_BLOB, BYTES_PER_COMMITMENT, BYTES_PER_FIELD_ELEMENT, BYTES_PER_PROOF,
-    FIELD_ELEMENTS_PER_BLOB, TRUSTED_SETUP_PATH,
+    blob_to_kzg_commitment_batch_rust, blob_to_kzg_commitment_rust,
+    blob_to_kzg_commitment_streaming_rust, blob_to_polynomial, bytes_to_blob,
+    compute_aggregate_kzg_proof_rust, compute_aggregate_kzg_proof_with_domains_rust,
+    compute_blob_kzg_proof_rust, compute_blob_kzg_proof_streaming_rust,
+    compute_blob_kzg_proof_streaming_with_domains_rust, compute_blob_kzg_proof_with_domains_rust,
+    compute_kzg_proof_rust, compute_kzg_proof_streaming_rust, compute_r_powers_with_digest,
+    evaluate_blob_in_evaluation_form, evaluate_polynomial_in_evaluation_form, hash,
+    kzg_to_versioned_hash, kzg_to_versioned_hashes, serialize_proofs_into,
+    verify_aggregate_kzg_proof_rust, verify_aggregate_kzg_proof_with_domains_rust,
+    verify_blob_bundle_rust, verify_blob_kzg_proof_batch_cancellable_rust,
+    verify_blob_kzg_proof_batch_rust, verify_blob_kzg_proof_batch_with_domains_rust,
+    verify_blob_kzg_proof_rust, verify_blob_kzg_proof_strict_rust,
+    verify_blob_kzg_proof_with_domains_rust, verify_kzg_proof_batch_rust,
+    verify_kzg_proof_batch_same_commitment_rust,
+    verify_kzg_proof_batch_same_commitment_with_domains_rust,
+    verify_kzg_proof_batch_with_domains_rust, verify_kzg_proof_rust, verify_kzg_proof_strict_rust,
+    verify_point_evaluation_precompile_rust, CancellableError, DomainConfig, BYTES_PER_BLOB,
+    BYTES_PER_COMMITMENT, BYTES_PER_FIELD_ELEMENT, BYTES_PER_PROOF, FIELD_ELEMENTS_PER_BLOB,
+    POINT_EVALUATION_INPUT_LENGTH, POINT_EVALUATION_OUTPUT, RANDOM_CHALLENGE_KZG_BATCH_DOMAIN,
+    TRUSTED_SETUP_PATH, VERSIONED_HASH_VERSION_KZG,
+};
+use kzg::encoding::{blob_to_data, data_to_blob, MAX_DATA_BYTES_PER_BLOB};
+use kzg::sidecar::build_blob_sidecars;
+#[cfg(feature = "ssz")]
+use kzg::ssz::{
+    blob_from_ssz, blob_to_ssz, g1_from_ssz, g1_list_from_ssz, g1_list_to_ssz, g1_to_ssz,
+    MAX_BLOB_COMMITMENTS_PER_BLOCK,
+};
+use kzg::{
+    FFTSettings, Fr, G1Affine, G1Fp, G1GetFp, G1LinComb, G1Mul, KZGSettings, PairingVerify, Poly,
+    G1, G2,
 };
-use kzg::{FFTSettings, Fr, G1Affine, G1Fp, G1GetFp, G1Mul, KZGSettings, Poly, G1, G2};
 use pathdiff::diff_paths;
 use rand::rngs::{OsRng, ThreadRng};
 use rand::Rng;
+use std::collections::HashMap;
 use std::env::current_dir;
 use std::fs;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread::{self, ThreadId};
+use std::time::{Duration, Instant};
 
 fn u64_to_bytes(x: u64) -> [u8; 32] {
     let mut bytes = [0u8; 32];
@@ -989,14 +1026,17 @@ pub fn compute_kzg_proof_empty_blob_vector_test<
         &TKZGSettings,
     ) -> Result<bool, String>,
 ) {
+    let res = verify_blob_kzg_proof_batch(&[], &[], &[], &TKZGSettings::default());
+    assert!(res.unwrap());
+
+    // Counts are checked before an empty batch is accepted
     let res = verify_blob_kzg_proof_batch(
         &[],
         &[TG1::default()],
         &[TG1::default()],
         &TKZGSettings::default(),
     );
-
-    assert!(res.unwrap());
+    assert!(res.is_err());
 }
 
 #[allow(clippy::type_complexity)]
@@ -1095,3 +1135,1389 @@ pub fn validate_batched_input_test<
 
     assert!(res.is_err());
 }
+
+#[allow(clippy::type_complexity)]
+pub fn compute_blob_kzg_proof_incorrect_blob_length_test<
+    TPoly: Poly<TFr>,
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    compute_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TKZGSettings) -> Result<TG1, String>,
+) {
+    let blob = vec![TFr::default(); FIELD_ELEMENTS_PER_BLOB + 1];
+
+    let res = compute_blob_kzg_proof(&blob, &TG1::identity(), &TKZGSettings::default());
+
+    assert!(res.is_err());
+}
+
+#[allow(clippy::type_complexity)]
+pub fn verify_blob_kzg_proof_batch_single_blob_missing_commitment_test<
+    TPoly: Poly<TFr>,
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    verify_blob_kzg_proof_batch: &dyn Fn(
+        &[Vec<TFr>],
+        &[TG1],
+        &[TG1],
+        &TKZGSettings,
+    ) -> Result<bool, String>,
+) {
+    let blob = vec![TFr::default(); FIELD_ELEMENTS_PER_BLOB];
+
+    let res =
+        verify_blob_kzg_proof_batch(&[blob], &[], &[TG1::default()], &TKZGSettings::default());
+
+    assert!(res.is_err());
+
+    // An empty batch only verifies when all three lists are empty
+    let ts = TKZGSettings::default();
+    assert!(verify_blob_kzg_proof_batch(&[], &[TG1::default()], &[], &ts).is_err());
+    assert!(verify_blob_kzg_proof_batch(&[], &[], &[TG1::default()], &ts).is_err());
+}
+
+#[allow(clippy::type_complexity)]
+pub fn kzg_settings_dimensions_test<
+    TPoly: Poly<TFr>,
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+
+    assert_eq!(ts.get_field_elements_per_blob(), FIELD_ELEMENTS_PER_BLOB);
+    assert_eq!(
+        ts.get_field_elements_per_ext_blob(),
+        2 * FIELD_ELEMENTS_PER_BLOB
+    );
+    assert_eq!(1 << ts.get_scale(), ts.get_field_elements_per_ext_blob());
+}
+
+/// Digest that hashes to zero, so the batch challenge r is always zero
+#[derive(Default)]
+struct ZeroDigest;
+
+impl Digest for ZeroDigest {
+    fn update(&mut self, _data: &[u8]) {}
+
+    fn finalize(self) -> [u8; 32] {
+        [0; 32]
+    }
+}
+
+/// Digest that hashes the batch challenge transcript to zero, but not the transcript followed by a
+/// re-derivation counter
+#[derive(Default)]
+struct ZeroOnceDigest {
+    inner: Sha2Digest,
+    data: Vec<u8>,
+}
+
+impl Digest for ZeroOnceDigest {
+    fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+        self.data.extend_from_slice(data);
+    }
+
+    fn finalize(self) -> [u8; 32] {
+        // The transcript is the domain tag, two lengths and a run of fixed-size items
+        let item_size = BYTES_PER_COMMITMENT + 2 * BYTES_PER_FIELD_ELEMENT + BYTES_PER_PROOF;
+        let is_transcript = self.data.starts_with(&RANDOM_CHALLENGE_KZG_BATCH_DOMAIN)
+            && self.data.len() >= 32
+            && self.data[32..]
+                .chunks_exact(item_size)
+                .remainder()
+                .is_empty();
+        if is_transcript {
+            [0; 32]
+        } else {
+            self.inner.finalize()
+        }
+    }
+}
+
+pub fn compute_r_powers_degenerate_challenge_test<TFr: Fr, TG1: G1>() {
+    let n = 3;
+    let commitments = vec![TG1::generator(); n];
+    let proofs = vec![TG1::generator(); n];
+    let zs = (0..n).map(|i| TFr::from_u64(i as u64)).collect::<Vec<_>>();
+    let ys = (0..n)
+        .map(|i| TFr::from_u64(2 * i as u64))
+        .collect::<Vec<_>>();
+
+    // Spec behavior keeps r = 0, so only the first item is weighted
+    let powers = compute_r_powers_with_digest::<TG1, TFr, ZeroDigest>(
+        &commitments,
+        &zs,
+        &ys,
+        &proofs,
+        false,
+    )
+    .unwrap();
+    assert_eq!(powers.len(), n);
+    assert!(powers[0].is_one());
+    assert!(powers[1..].iter().all(|power| power.is_zero()));
+
+    // Strict mode gives up if every re-derivation is degenerate too
+    assert!(compute_r_powers_with_digest::<TG1, TFr, ZeroDigest>(
+        &commitments,
+        &zs,
+        &ys,
+        &proofs,
+        true
+    )
+    .is_err());
+
+    // Strict mode re-derives r once the first attempt is degenerate
+    let powers = compute_r_powers_with_digest::<TG1, TFr, ZeroOnceDigest>(
+        &commitments,
+        &zs,
+        &ys,
+        &proofs,
+        true,
+    )
+    .unwrap();
+    assert!(powers[0].is_one());
+    assert!(!powers[1].is_zero() && !powers[1].is_one());
+    assert!(powers[2].equals(&powers[1].mul(&powers[1])));
+
+    // Non-degenerate challenges are the same in both modes
+    let spec = compute_r_powers_with_digest::<TG1, TFr, Sha2Digest>(
+        &commitments,
+        &zs,
+        &ys,
+        &proofs,
+        false,
+    )
+    .unwrap();
+    let strict =
+        compute_r_powers_with_digest::<TG1, TFr, Sha2Digest>(&commitments, &zs, &ys, &proofs, true)
+            .unwrap();
+    for (a, b) in spec.iter().zip(strict.iter()) {
+        assert!(a.equals(b));
+    }
+
+    // Every list must hold one entry per commitment
+    assert!(compute_r_powers_with_digest::<TG1, TFr, Sha2Digest>(
+        &commitments,
+        &zs[1..],
+        &ys,
+        &proofs,
+        false
+    )
+    .is_err());
+    assert!(compute_r_powers_with_digest::<TG1, TFr, Sha2Digest>(
+        &commitments,
+        &zs,
+        &ys[1..],
+        &proofs,
+        false
+    )
+    .is_err());
+    assert!(compute_r_powers_with_digest::<TG1, TFr, Sha2Digest>(
+        &commitments,
+        &zs,
+        &ys,
+        &proofs[1..],
+        false
+    )
+    .is_err());
+}
+
+#[allow(clippy::type_complexity)]
+pub fn verify_identity_strict_test<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let blob = vec![TFr::zero(); FIELD_ELEMENTS_PER_BLOB];
+
+    // The zero blob commits to the identity, and so do its proofs
+    let commitment = blob_to_kzg_commitment_rust(&blob, &ts).unwrap();
+    assert!(commitment.is_inf());
+
+    let z = TFr::from_u64(0x4844);
+    let (proof, y) = compute_kzg_proof_rust(&blob, &z, &ts).unwrap();
+    assert!(proof.is_inf());
+    assert!(y.is_zero());
+    assert!(verify_kzg_proof_rust(&commitment, &z, &y, &proof, &ts).unwrap());
+    assert_eq!(
+        verify_kzg_proof_strict_rust(&commitment, &z, &y, &proof, &ts),
+        Err("Identity commitment".to_string())
+    );
+
+    let blob_proof = compute_blob_kzg_proof_rust(&blob, &commitment, &ts).unwrap();
+    assert!(verify_blob_kzg_proof_rust(&blob, &commitment, &blob_proof, &ts).unwrap());
+    assert_eq!(
+        verify_blob_kzg_proof_strict_rust(&blob, &commitment, &blob_proof, &ts),
+        Err("Identity commitment".to_string())
+    );
+
+    // A regular commitment still goes through, unless its proof is the identity
+    let mut blob = blob;
+    blob[0] = TFr::one();
+    let commitment = blob_to_kzg_commitment_rust(&blob, &ts).unwrap();
+    let blob_proof = compute_blob_kzg_proof_rust(&blob, &commitment, &ts).unwrap();
+    assert!(verify_blob_kzg_proof_strict_rust(&blob, &commitment, &blob_proof, &ts).unwrap());
+    assert_eq!(
+        verify_blob_kzg_proof_strict_rust(&blob, &commitment, &TG1::identity(), &ts),
+        Err("Identity proof".to_string())
+    );
+}
+
+// Blob that raises `cancel` when the verifier starts on it. Counts the blob steps, and for each
+// thread that runs one, the steps it starts after the flag is already visible
+struct CancelHook<'a, TFr> {
+    blob: &'a [TFr],
+    raises_flag: bool,
+    cancel: &'a AtomicBool,
+    steps: &'a AtomicUsize,
+    late_steps: &'a Mutex<HashMap<ThreadId, usize>>,
+}
+
+impl<TFr> AsRef<[TFr]> for CancelHook<'_, TFr> {
+    fn as_ref(&self) -> &[TFr] {
+        self.steps.fetch_add(1, Ordering::Relaxed);
+        let late = usize::from(self.cancel.load(Ordering::Relaxed));
+        *self
+            .late_steps
+            .lock()
+            .unwrap()
+            .entry(thread::current().id())
+            .or_default() += late;
+        if self.raises_flag {
+            self.cancel.store(true, Ordering::Relaxed);
+        }
+        self.blob
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn verify_blob_kzg_proof_batch_cancellable_test<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine> + PairingVerify<TG1, TG2>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine> + Sync,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+
+    const N_SAMPLES: usize = 16;
+
+    let blobs: Vec<Vec<TFr>> = (0..N_SAMPLES)
+        .map(|_| bytes_to_blob(&generate_random_blob_bytes(&mut rng)).unwrap())
+        .collect();
+    let commitments: Vec<TG1> = blobs
+        .iter()
+        .map(|blob| blob_to_kzg_commitment_rust(blob, &ts).unwrap())
+        .collect();
+    let proofs: Vec<TG1> = blobs
+        .iter()
+        .zip(commitments.iter())
+        .map(|(blob, commitment)| compute_blob_kzg_proof_rust(blob, commitment, &ts).unwrap())
+        .collect();
+
+    let cancel = AtomicBool::new(false);
+    assert_eq!(
+        verify_blob_kzg_proof_batch_cancellable_rust(&blobs, &commitments, &proofs, &ts, &cancel),
+        Ok(true)
+    );
+
+    // Rejected inputs are told apart from a cancellation
+    assert_eq!(
+        verify_blob_kzg_proof_batch_cancellable_rust(
+            &blobs[1..],
+            &commitments,
+            &proofs,
+            &ts,
+            &cancel
+        ),
+        Err(CancellableError::Failed(
+            "Invalid amount of arguments".to_string()
+        ))
+    );
+
+    // A flag raised up front stops the batch before any work
+    cancel.store(true, Ordering::Relaxed);
+    assert_eq!(
+        verify_blob_kzg_proof_batch_cancellable_rust(&blobs, &commitments, &proofs, &ts, &cancel),
+        Err(CancellableError::Cancelled)
+    );
+    // Including a single blob, which skips the batch check
+    assert_eq!(
+        verify_blob_kzg_proof_batch_cancellable_rust(
+            &blobs[..1],
+            &commitments[..1],
+            &proofs[..1],
+            &ts,
+            &cancel
+        ),
+        Err(CancellableError::Cancelled)
+    );
+
+    // Raised from another thread, the flag stops one of the batches verified in a loop
+    cancel.store(false, Ordering::Relaxed);
+    let deadline = Instant::now() + Duration::from_secs(60);
+    thread::scope(|scope| {
+        scope.spawn(|| {
+            thread::sleep(Duration::from_millis(20));
+            cancel.store(true, Ordering::Relaxed);
+        });
+
+        loop {
+            assert!(Instant::now() < deadline, "Batch was not cancelled");
+            match verify_blob_kzg_proof_batch_cancellable_rust(
+                &blobs,
+                &commitments,
+                &proofs,
+                &ts,
+                &cancel,
+            ) {
+                Ok(result) => assert!(result),
+                Err(err) => {
+                    assert_eq!(err, CancellableError::Cancelled);
+                    break;
+                }
+            }
+        }
+    });
+
+    // Raised while a blob is being processed, the flag lets each worker finish at most the step
+    // it is in. Raising it on the last blob checks that no multi-scalar multiplication follows
+    for raising_blob in [0, N_SAMPLES / 2, N_SAMPLES - 1] {
+        cancel.store(false, Ordering::Relaxed);
+        let steps = AtomicUsize::new(0);
+        let late_steps = Mutex::new(HashMap::new());
+        let hooked: Vec<CancelHook<TFr>> = blobs
+            .iter()
+            .enumerate()
+            .map(|(i, blob)| CancelHook {
+                blob,
+                raises_flag: i == raising_blob,
+                cancel: &cancel,
+                steps: &steps,
+                late_steps: &late_steps,
+            })
+            .collect();
+
+        let result = verify_blob_kzg_proof_batch_cancellable_rust(
+            &hooked,
+            &commitments,
+            &proofs,
+            &ts,
+            &cancel,
+        );
+        let late_steps = late_steps.into_inner().unwrap();
+        assert!(late_steps.values().all(|&n| n <= 1));
+        match result {
+            // Only blobs verified one by one on several threads may all finish with no check
+            // left to run. On a single thread the check before the multiplications must catch it
+            Ok(result) => {
+                assert!(result && steps.into_inner() == N_SAMPLES && late_steps.len() > 1)
+            }
+            Err(err) => assert_eq!(err, CancellableError::Cancelled),
+        }
+    }
+}
+
+#[cfg(feature = "commitment-cache")]
+#[allow(clippy::type_complexity)]
+pub fn commitment_cache_test<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+
+    const N_SAMPLES: usize = 3;
+
+    let blobs: Vec<Vec<TFr>> = (0..N_SAMPLES)
+        .map(|_| bytes_to_blob(&generate_random_blob_bytes(&mut rng)).unwrap())
+        .collect();
+    let commitments: Vec<TG1> = blobs
+        .iter()
+        .map(|blob| blob_to_kzg_commitment_rust(blob, &ts).unwrap())
+        .collect();
+    let proofs: Vec<TG1> = blobs
+        .iter()
+        .zip(commitments.iter())
+        .map(|(blob, commitment)| compute_blob_kzg_proof_rust(blob, commitment, &ts).unwrap())
+        .collect();
+    let commitment_bytes: Vec<[u8; BYTES_PER_COMMITMENT]> =
+        commitments.iter().map(|c| c.to_bytes()).collect();
+
+    // Misses fill the cache, repeated lookups hit it
+    let mut cache = CommitmentCache::<TG1>::new(2);
+    assert!(cache.get(&commitment_bytes[0]).is_none());
+    assert!(cache
+        .get_or_insert(&commitment_bytes[0])
+        .unwrap()
+        .equals(&commitments[0]));
+    assert!(cache
+        .get(&commitment_bytes[0])
+        .unwrap()
+        .equals(&commitments[0]));
+    assert_eq!((cache.hits(), cache.misses()), (1, 2));
+
+    // The least recently used entry goes first
+    cache.insert(&commitment_bytes[1]).unwrap();
+    cache.get(&commitment_bytes[0]).unwrap();
+    cache.insert(&commitment_bytes[2]).unwrap();
+    assert_eq!(cache.len(), 2);
+    assert!(cache.get(&commitment_bytes[1]).is_none());
+    assert!(cache.get(&commitment_bytes[0]).is_some());
+    assert!(cache.get(&commitment_bytes[2]).is_some());
+
+    cache.set_capacity(1);
+    assert_eq!(cache.len(), 1);
+    assert!(cache.get(&commitment_bytes[2]).is_some());
+
+    // Bytes that do not decode to a valid point are never cached
+    let mut bad_bytes = commitment_bytes[0];
+    bad_bytes[BYTES_PER_COMMITMENT - 1] ^= 1;
+    if TG1::from_bytes(&bad_bytes).is_err() {
+        assert!(cache.insert(&bad_bytes).is_err());
+        assert!(cache.get(&bad_bytes).is_none());
+    }
+
+    // Cached and uncached verification agree, on both correct and mismatched proofs
+    cache.clear();
+    cache.set_capacity(N_SAMPLES);
+    for _ in 0..2 {
+        for i in 0..N_SAMPLES {
+            for (j, proof) in proofs.iter().enumerate() {
+                let proof_bytes = proof.to_bytes();
+                let cached = verify_blob_kzg_proof_cached_rust(
+                    &blobs[i],
+                    &commitment_bytes[i],
+                    &proof_bytes,
+                    &ts,
+                    &mut cache,
+                );
+                let uncached = verify_blob_kzg_proof_rust(&blobs[i], &commitments[i], proof, &ts);
+                assert_eq!(cached, uncached);
+                assert_eq!(cached, Ok(i == j));
+            }
+        }
+    }
+    assert_eq!(cache.len(), N_SAMPLES);
+}
+
+#[allow(clippy::type_complexity)]
+pub fn zero_and_constant_blob_test<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine> + PairingVerify<TG1, TG2>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine> + Sync,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let z = TFr::from_u64(0x4844);
+
+    let mut identity_bytes = [0u8; BYTES_PER_COMMITMENT];
+    identity_bytes[0] = 0xc0;
+    for value in [TFr::zero(), TFr::from_u64(7)] {
+        let blob = vec![value; FIELD_ELEMENTS_PER_BLOB];
+
+        // The setup is in Lagrange form, whose points sum up to the generator
+        let commitment = blob_to_kzg_commitment_rust(&blob, &ts).unwrap();
+        assert!(commitment.equals(&TG1::generator().mul(&value)));
+        assert_eq!(commitment.is_inf(), value.is_zero());
+        if value.is_zero() {
+            assert_eq!(commitment.to_bytes(), identity_bytes);
+        }
+
+        // The quotient of a constant polynomial is zero
+        let (proof, y) = compute_kzg_proof_rust(&blob, &z, &ts).unwrap();
+        assert!(proof.is_inf());
+        assert!(y.equals(&value));
+        assert!(verify_kzg_proof_rust(&commitment, &z, &y, &proof, &ts).unwrap());
+        assert!(!verify_kzg_proof_rust(&commitment, &z, &y.add(&TFr::one()), &proof, &ts).unwrap());
+
+        let blob_proof = compute_blob_kzg_proof_rust(&blob, &commitment, &ts).unwrap();
+        assert_eq!(blob_proof.to_bytes(), identity_bytes);
+        assert!(verify_blob_kzg_proof_rust(&blob, &commitment, &blob_proof, &ts).unwrap());
+    }
+
+    // Degenerate blobs batch together with a regular one
+    let mut rng = rand::thread_rng();
+    let blobs = vec![
+        vec![TFr::zero(); FIELD_ELEMENTS_PER_BLOB],
+        vec![TFr::from_u64(7); FIELD_ELEMENTS_PER_BLOB],
+        bytes_to_blob(&generate_random_blob_bytes(&mut rng)).unwrap(),
+    ];
+    let commitments: Vec<TG1> = blobs
+        .iter()
+        .map(|blob| blob_to_kzg_commitment_rust(blob, &ts).unwrap())
+        .collect();
+    let proofs: Vec<TG1> = blobs
+        .iter()
+        .zip(commitments.iter())
+        .map(|(blob, commitment)| compute_blob_kzg_proof_rust(blob, commitment, &ts).unwrap())
+        .collect();
+    assert!(verify_blob_kzg_proof_batch_rust(&blobs, &commitments, &proofs, &ts).unwrap());
+}
+
+#[allow(clippy::type_complexity)]
+pub fn blob_to_kzg_commitment_batch_test<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine> + Sync,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+
+    let blobs: Vec<Vec<TFr>> = (0..5)
+        .map(|_| bytes_to_blob(&generate_random_blob_bytes(&mut rng)).unwrap())
+        .collect();
+    let commitments = blob_to_kzg_commitment_batch_rust(&blobs, &ts).unwrap();
+    assert_eq!(commitments.len(), blobs.len());
+    for (blob, commitment) in blobs.iter().zip(&commitments) {
+        assert!(commitment.equals(&blob_to_kzg_commitment_rust(blob, &ts).unwrap()));
+    }
+
+    assert!(blob_to_kzg_commitment_batch_rust(&[] as &[Vec<TFr>], &ts)
+        .unwrap()
+        .is_empty());
+
+    // One malformed blob fails the whole batch
+    let mut bad_blobs = blobs;
+    bad_blobs[2].pop();
+    assert!(blob_to_kzg_commitment_batch_rust(&bad_blobs, &ts).is_err());
+}
+
+#[allow(clippy::type_complexity)]
+pub fn blob_newtype_test<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+
+    let bytes = generate_random_blob_bytes(&mut rng);
+    let blob = Blob::<TFr>::from_bytes(&bytes).unwrap();
+    assert_eq!(blob.to_bytes(), bytes);
+    assert_eq!(blob.len(), FIELD_ELEMENTS_PER_BLOB);
+
+    // The blob can be used wherever a slice of field elements is expected
+    let commitment = blob_to_kzg_commitment_rust(&blob, &ts).unwrap();
+    let elements: Vec<TFr> = bytes_to_blob(&bytes).unwrap();
+    assert!(commitment.equals(&blob_to_kzg_commitment_rust(&elements, &ts).unwrap()));
+
+    let polynomial: TPoly = blob.as_polynomial();
+    let expected: TPoly = blob_to_polynomial(&elements).unwrap();
+    assert_eq!(polynomial.len(), expected.len());
+    assert!(
+        (0..expected.len()).all(|i| polynomial.get_coeff_at(i).equals(&expected.get_coeff_at(i)))
+    );
+
+    assert!(Blob::try_from(elements.clone()).unwrap() == blob);
+    assert!(blob.clone().into_inner() == elements);
+
+    // Wrong lengths and non-canonical elements are rejected
+    assert!(Blob::<TFr>::new(elements[1..].to_vec()).is_err());
+    assert!(Blob::<TFr>::from_bytes(&bytes[1..]).is_err());
+    let mut non_canonical = bytes;
+    non_canonical[..BYTES_PER_FIELD_ELEMENT].fill(0xff);
+    assert!(Blob::<TFr>::from_bytes(&non_canonical).is_err());
+}
+
+pub fn kzg_to_versioned_hash_test<TG1: G1>() {
+    // Versioned hash of the commitment to an all-zero blob
+    let expected: [u8; 32] = [
+        0x01, 0x06, 0x57, 0xf3, 0x75, 0x54, 0xc7, 0x81, 0x40, 0x2a, 0x22, 0x91, 0x7d, 0xee, 0x2f,
+        0x75, 0xde, 0xf7, 0xab, 0x96, 0x6d, 0x7b, 0x77, 0x09, 0x05, 0x39, 0x8e, 0xba, 0x3c, 0x44,
+        0x40, 0x14,
+    ];
+    assert_eq!(kzg_to_versioned_hash(&TG1::identity()), expected);
+
+    let commitments = [TG1::identity(), TG1::generator(), TG1::generator().dbl()];
+    let hashes = kzg_to_versioned_hashes(&commitments);
+    assert_eq!(hashes.len(), commitments.len());
+    for (commitment, versioned_hash) in commitments.iter().zip(&hashes) {
+        assert_eq!(versioned_hash[0], VERSIONED_HASH_VERSION_KZG);
+        assert_eq!(versioned_hash[1..], hash(&commitment.to_bytes())[1..]);
+        assert_eq!(*versioned_hash, kzg_to_versioned_hash(commitment));
+    }
+}
+
+pub fn data_blob_codec_test<TFr: Fr>() {
+    let mut rng = rand::thread_rng();
+    for len in [0, 100, MAX_DATA_BYTES_PER_BLOB] {
+        let data: Vec<u8> = (0..len).map(|_| rng.gen()).collect();
+        let blob: Vec<TFr> = data_to_blob(&data).unwrap();
+        assert_eq!(blob.len(), FIELD_ELEMENTS_PER_BLOB);
+        assert_eq!(blob_to_data(&blob).unwrap(), data);
+    }
+
+    // An element that uses its first byte is not produced by the encoder
+    let mut blob: Vec<TFr> = data_to_blob(b"data").unwrap();
+    blob[FIELD_ELEMENTS_PER_BLOB - 1] = TFr::one().negate();
+    assert!(blob_to_data(&blob).is_err());
+}
+
+#[allow(clippy::type_complexity)]
+pub fn blob_to_kzg_commitment_streaming_test<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+
+    let bytes = generate_random_blob_bytes(&mut rng);
+    let elements = || {
+        bytes
+            .chunks_exact(BYTES_PER_FIELD_ELEMENT)
+            .map(|chunk| <[u8; BYTES_PER_FIELD_ELEMENT]>::try_from(chunk).unwrap())
+    };
+
+    let blob: Vec<TFr> = bytes_to_blob(&bytes).unwrap();
+    let expected = blob_to_kzg_commitment_rust(&blob, &ts).unwrap();
+    let commitment = blob_to_kzg_commitment_streaming_rust(elements(), &ts).unwrap();
+    assert!(commitment.equals(&expected));
+
+    // Short, long and non-canonical streams are rejected
+    let short = elements().take(FIELD_ELEMENTS_PER_BLOB - 1);
+    assert!(blob_to_kzg_commitment_streaming_rust(short, &ts).is_err());
+    let long = elements().chain(core::iter::once([0u8; BYTES_PER_FIELD_ELEMENT]));
+    assert!(blob_to_kzg_commitment_streaming_rust(long, &ts).is_err());
+    let non_canonical = elements().enumerate().map(|(i, element)| {
+        if i == 300 {
+            [0xff; BYTES_PER_FIELD_ELEMENT]
+        } else {
+            element
+        }
+    });
+    assert!(blob_to_kzg_commitment_streaming_rust(non_canonical, &ts).is_err());
+
+    // A setup too small for a full blob is rejected before reading the stream
+    let small_ts = TKZGSettings::new(
+        &ts.get_g1_secret()[..16],
+        ts.get_g2_secret(),
+        16,
+        ts.get_fft_settings(),
+    )
+    .unwrap();
+    let mut stream = elements();
+    assert!(blob_to_kzg_commitment_streaming_rust(stream.by_ref(), &small_ts).is_err());
+    assert_eq!(stream.count(), FIELD_ELEMENTS_PER_BLOB);
+}
+
+#[allow(clippy::type_complexity)]
+pub fn compute_blob_kzg_proof_streaming_test<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+
+    let bytes = generate_random_blob_bytes(&mut rng);
+    let elements = || {
+        bytes
+            .chunks_exact(BYTES_PER_FIELD_ELEMENT)
+            .map(|chunk| <[u8; BYTES_PER_FIELD_ELEMENT]>::try_from(chunk).unwrap())
+    };
+
+    let blob: Vec<TFr> = bytes_to_blob(&bytes).unwrap();
+    let commitment = blob_to_kzg_commitment_rust(&blob, &ts).unwrap();
+    let expected = compute_blob_kzg_proof_rust(&blob, &commitment, &ts).unwrap();
+    let proof = compute_blob_kzg_proof_streaming_rust(elements, &commitment, &ts).unwrap();
+    assert!(proof.equals(&expected));
+
+    // Both outside the domain and at one of its points, past the first chunk
+    let root = ts.get_fft_settings().get_roots_of_unity()[300];
+    for z in [TFr::rand(), root] {
+        let (expected_proof, expected_y) = compute_kzg_proof_rust(&blob, &z, &ts).unwrap();
+        let (proof, y) = compute_kzg_proof_streaming_rust(elements, &z, &ts).unwrap();
+        assert!(proof.equals(&expected_proof));
+        assert!(y.equals(&expected_y));
+    }
+
+    // Short, long and non-canonical streams are rejected
+    let short = || elements().take(FIELD_ELEMENTS_PER_BLOB - 1);
+    assert!(compute_blob_kzg_proof_streaming_rust(short, &commitment, &ts).is_err());
+    let long = || elements().chain(core::iter::once([0u8; BYTES_PER_FIELD_ELEMENT]));
+    assert!(compute_blob_kzg_proof_streaming_rust(long, &commitment, &ts).is_err());
+    let non_canonical = || {
+        elements().enumerate().map(|(i, element)| {
+            if i == 300 {
+                [0xff; BYTES_PER_FIELD_ELEMENT]
+            } else {
+                element
+            }
+        })
+    };
+    assert!(compute_blob_kzg_proof_streaming_rust(non_canonical, &commitment, &ts).is_err());
+    assert!(compute_kzg_proof_streaming_rust(non_canonical, &TFr::one(), &ts).is_err());
+
+    let small_ts = TKZGSettings::new(
+        &ts.get_g1_secret()[..16],
+        ts.get_g2_secret(),
+        16,
+        ts.get_fft_settings(),
+    )
+    .unwrap();
+    assert!(compute_blob_kzg_proof_streaming_rust(elements, &commitment, &small_ts).is_err());
+}
+
+pub fn serialize_proofs_into_test<TFr: Fr, TG1: G1 + G1Mul<TFr>>() {
+    let proofs = (0..5)
+        .map(|i| TG1::generator().mul(&TFr::from_u64(i)))
+        .collect::<Vec<_>>();
+    let expected = proofs
+        .iter()
+        .flat_map(|proof| proof.to_bytes())
+        .collect::<Vec<_>>();
+
+    let mut out = vec![0u8; proofs.len() * BYTES_PER_PROOF];
+    serialize_proofs_into(&proofs, &mut out).unwrap();
+    assert_eq!(out, expected);
+
+    serialize_proofs_into::<TG1>(&[], &mut []).unwrap();
+    assert!(serialize_proofs_into(&proofs, &mut out[1..]).is_err());
+    assert!(serialize_proofs_into(&proofs[1..], &mut out).is_err());
+}
+
+#[cfg(feature = "ssz")]
+pub fn ssz_round_trip_test<TFr: Fr, TG1: G1>() {
+    let mut rng = rand::thread_rng();
+
+    let bytes = generate_random_blob_bytes(&mut rng);
+    let blob: Vec<TFr> = blob_from_ssz(&bytes).unwrap();
+    assert_eq!(blob_to_ssz(&blob).unwrap(), bytes);
+    assert!(blob_to_ssz(&blob[1..]).is_err());
+
+    let points = vec![TG1::identity(), TG1::generator(), TG1::rand()];
+    for point in &points {
+        let encoded = g1_to_ssz(point);
+        assert_eq!(encoded, point.to_bytes());
+        assert!(g1_from_ssz::<TG1>(&encoded).unwrap().equals(point));
+        assert!(g1_from_ssz::<TG1>(&encoded[1..]).is_err());
+    }
+
+    let encoded = g1_list_to_ssz(&points, MAX_BLOB_COMMITMENTS_PER_BLOCK).unwrap();
+    assert_eq!(encoded.len(), points.len() * BYTES_PER_COMMITMENT);
+    let decoded: Vec<TG1> = g1_list_from_ssz(&encoded, MAX_BLOB_COMMITMENTS_PER_BLOCK).unwrap();
+    assert_eq!(decoded.len(), points.len());
+    assert!(decoded.iter().zip(&points).all(|(a, b)| a.equals(b)));
+    assert!(g1_list_from_ssz::<TG1>(&[], MAX_BLOB_COMMITMENTS_PER_BLOCK)
+        .unwrap()
+        .is_empty());
+
+    // Too many items, or a truncated item, are rejected
+    assert!(g1_list_to_ssz(&points, 2).is_err());
+    assert!(g1_list_from_ssz::<TG1>(&encoded, 2).is_err());
+    assert!(g1_list_from_ssz::<TG1>(&encoded[1..], MAX_BLOB_COMMITMENTS_PER_BLOCK).is_err());
+}
+
+#[allow(clippy::type_complexity)]
+pub fn verify_point_evaluation_precompile_test<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+
+    let blob: Vec<TFr> = bytes_to_blob(&generate_random_blob_bytes(&mut rng)).unwrap();
+    let commitment = blob_to_kzg_commitment_rust(&blob, &ts).unwrap();
+    let z = TFr::from_u64(0x4844);
+    let (proof, y) = compute_kzg_proof_rust(&blob, &z, &ts).unwrap();
+
+    let mut input = [0u8; POINT_EVALUATION_INPUT_LENGTH];
+    input[..32].copy_from_slice(&kzg_to_versioned_hash(&commitment));
+    input[32..64].copy_from_slice(&z.to_bytes());
+    input[64..96].copy_from_slice(&y.to_bytes());
+    input[96..144].copy_from_slice(&commitment.to_bytes());
+    input[144..].copy_from_slice(&proof.to_bytes());
+    assert!(verify_point_evaluation_precompile_rust(&input, &ts).unwrap());
+
+    // A wrong evaluation is a failed proof, not a malformed input
+    let mut wrong_y = input;
+    wrong_y[64..96].copy_from_slice(&y.add(&TFr::one()).to_bytes());
+    assert!(!verify_point_evaluation_precompile_rust(&wrong_y, &ts).unwrap());
+
+    let mut wrong_hash = input;
+    wrong_hash[0] = 0x02;
+    assert!(verify_point_evaluation_precompile_rust(&wrong_hash, &ts).is_err());
+
+    let mut non_canonical_z = input;
+    non_canonical_z[32..64].fill(0xff);
+    assert!(verify_point_evaluation_precompile_rust(&non_canonical_z, &ts).is_err());
+
+    // The success output ends with the modulus, one above the largest field element
+    let mut field_elements_per_blob = [0u8; 32];
+    field_elements_per_blob[24..].copy_from_slice(&(FIELD_ELEMENTS_PER_BLOB as u64).to_be_bytes());
+    assert_eq!(POINT_EVALUATION_OUTPUT[..32], field_elements_per_blob);
+    let modulus = &POINT_EVALUATION_OUTPUT[32..];
+    assert!(TFr::from_bytes(modulus).is_err());
+    let mut max_element = [0u8; 32];
+    max_element.copy_from_slice(modulus);
+    max_element[31] -= 1;
+    assert!(TFr::from_bytes(&max_element)
+        .unwrap()
+        .equals(&TFr::one().negate()));
+}
+
+#[allow(clippy::type_complexity)]
+pub fn aggregate_kzg_proof_test<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+
+    let blobs: Vec<Vec<TFr>> = (0..3)
+        .map(|_| bytes_to_blob(&generate_random_blob_bytes(&mut rng)).unwrap())
+        .collect();
+    let commitments: Vec<TG1> = blobs
+        .iter()
+        .map(|blob| blob_to_kzg_commitment_rust(blob, &ts).unwrap())
+        .collect();
+
+    let proof = compute_aggregate_kzg_proof_rust(&blobs, &commitments, &ts).unwrap();
+    assert!(verify_aggregate_kzg_proof_rust(&blobs, &commitments, &proof, &ts).unwrap());
+
+    let single = compute_aggregate_kzg_proof_rust(&blobs[..1], &commitments[..1], &ts).unwrap();
+    assert!(verify_aggregate_kzg_proof_rust(&blobs[..1], &commitments[..1], &single, &ts).unwrap());
+
+    // The proof covers exactly these blobs, commitments and their order
+    assert!(!verify_aggregate_kzg_proof_rust(&blobs, &commitments, &single, &ts).unwrap());
+    assert!(!verify_aggregate_kzg_proof_rust(&blobs[..2], &commitments[..2], &proof, &ts).unwrap());
+    let mut swapped = commitments.clone();
+    swapped.swap(0, 1);
+    assert!(!verify_aggregate_kzg_proof_rust(&blobs, &swapped, &proof, &ts).unwrap());
+    let mut altered = blobs.clone();
+    altered[2][7] = altered[2][7].add(&TFr::one());
+    assert!(!verify_aggregate_kzg_proof_rust(&altered, &commitments, &proof, &ts).unwrap());
+
+    assert!(compute_aggregate_kzg_proof_rust(&blobs, &commitments[..2], &ts).is_err());
+    assert!(verify_aggregate_kzg_proof_rust(&[] as &[Vec<TFr>], &[], &proof, &ts).is_err());
+}
+
+#[allow(clippy::type_complexity)]
+pub fn verify_kzg_proof_batch_same_commitment_test<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine> + PairingVerify<TG1, TG2>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+
+    let blob: Vec<TFr> = bytes_to_blob(&generate_random_blob_bytes(&mut rng)).unwrap();
+    let commitment = blob_to_kzg_commitment_rust(&blob, &ts).unwrap();
+
+    let zs: Vec<TFr> = (0..5).map(|_| TFr::rand()).collect();
+    let (proofs, mut ys): (Vec<TG1>, Vec<TFr>) = zs
+        .iter()
+        .map(|z| compute_kzg_proof_rust(&blob, z, &ts).unwrap())
+        .unzip();
+
+    assert!(
+        verify_kzg_proof_batch_same_commitment_rust(&commitment, &zs, &ys, &proofs, &ts).unwrap()
+    );
+    assert!(verify_kzg_proof_batch_same_commitment_rust(
+        &commitment,
+        &zs[..1],
+        &ys[..1],
+        &proofs[..1],
+        &ts
+    )
+    .unwrap());
+    assert!(verify_kzg_proof_batch_same_commitment_rust(&commitment, &[], &[], &[], &ts).unwrap());
+
+    // Another commitment, or one wrong evaluation, fails the whole batch
+    let other = blob_to_kzg_commitment_rust(&[TFr::one(); FIELD_ELEMENTS_PER_BLOB], &ts).unwrap();
+    assert!(!verify_kzg_proof_batch_same_commitment_rust(&other, &zs, &ys, &proofs, &ts).unwrap());
+    ys[3] = ys[3].add(&TFr::one());
+    assert!(
+        !verify_kzg_proof_batch_same_commitment_rust(&commitment, &zs, &ys, &proofs, &ts).unwrap()
+    );
+
+    assert!(
+        verify_kzg_proof_batch_same_commitment_rust(&commitment, &zs, &ys[1..], &proofs, &ts)
+            .is_err()
+    );
+}
+
+/// Check that verifying openings of one commitment agrees with the general batch given that
+/// commitment once per opening
+#[allow(clippy::type_complexity)]
+pub fn verify_kzg_proof_batch_same_commitment_matches_general_test<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine> + PairingVerify<TG1, TG2>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+
+    let blob: Vec<TFr> = bytes_to_blob(&generate_random_blob_bytes(&mut rng)).unwrap();
+    let commitment = blob_to_kzg_commitment_rust(&blob, &ts).unwrap();
+    let other = blob_to_kzg_commitment_rust(&[TFr::one(); FIELD_ELEMENTS_PER_BLOB], &ts).unwrap();
+
+    let zs: Vec<TFr> = (0..5).map(|_| TFr::rand()).collect();
+    let (proofs, ys): (Vec<TG1>, Vec<TFr>) = zs
+        .iter()
+        .map(|z| compute_kzg_proof_rust(&blob, z, &ts).unwrap())
+        .unzip();
+    let mut wrong_ys = ys.clone();
+    wrong_ys[3] = wrong_ys[3].add(&TFr::one());
+    let mut swapped_proofs = proofs.clone();
+    swapped_proofs.swap(0, 1);
+
+    let cases: [(&TG1, &[TFr], &[TFr], &[TG1]); 6] = [
+        (&commitment, &zs, &ys, &proofs),
+        (&commitment, &zs[..1], &ys[..1], &proofs[..1]),
+        (&other, &zs, &ys, &proofs),
+        (&commitment, &zs, &wrong_ys, &proofs),
+        (&commitment, &zs, &ys, &swapped_proofs),
+        (&commitment, &zs, &ys[1..], &proofs),
+    ];
+    for (commitment, zs, ys, proofs) in cases {
+        let commitments = vec![commitment.clone(); zs.len()];
+        assert_eq!(
+            verify_kzg_proof_batch_same_commitment_rust(commitment, zs, ys, proofs, &ts),
+            verify_kzg_proof_batch_rust(&commitments, zs, ys, proofs, &ts)
+        );
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn custom_domains_test<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine> + PairingVerify<TG1, TG2>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine> + Sync,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+    let custom = DomainConfig {
+        fiat_shamir_protocol: *b"OTHERCHAIN_FS_1_",
+        random_challenge_kzg_batch: *b"OTHERCHAIN_RC_1_",
+        aggregate_proof: *b"OTHERCHAIN_AG_1_",
+    };
+    assert_eq!(DomainConfig::default(), DomainConfig::SPEC);
+
+    let blobs: Vec<Vec<TFr>> = (0..2)
+        .map(|_| bytes_to_blob(&generate_random_blob_bytes(&mut rng)).unwrap())
+        .collect();
+    let commitments: Vec<TG1> = blobs
+        .iter()
+        .map(|blob| blob_to_kzg_commitment_rust(blob, &ts).unwrap())
+        .collect();
+
+    // The spec tags give the usual proofs
+    let spec_proof = compute_blob_kzg_proof_with_domains_rust(
+        &blobs[0],
+        &commitments[0],
+        &ts,
+        &DomainConfig::SPEC,
+    )
+    .unwrap();
+    assert!(
+        spec_proof.equals(&compute_blob_kzg_proof_rust(&blobs[0], &commitments[0], &ts).unwrap())
+    );
+
+    // Proofs made with other tags only verify with the same tags
+    let proofs: Vec<TG1> = blobs
+        .iter()
+        .zip(&commitments)
+        .map(|(blob, commitment)| {
+            compute_blob_kzg_proof_with_domains_rust(blob, commitment, &ts, &custom).unwrap()
+        })
+        .collect();
+    assert!(!proofs[0].equals(&spec_proof));
+    assert!(verify_blob_kzg_proof_with_domains_rust(
+        &blobs[0],
+        &commitments[0],
+        &proofs[0],
+        &ts,
+        &custom
+    )
+    .unwrap());
+    assert!(!verify_blob_kzg_proof_rust(&blobs[0], &commitments[0], &proofs[0], &ts).unwrap());
+
+    assert!(verify_blob_kzg_proof_batch_with_domains_rust(
+        &blobs,
+        &commitments,
+        &proofs,
+        &ts,
+        &custom
+    )
+    .unwrap());
+    assert!(!verify_blob_kzg_proof_batch_rust(&blobs, &commitments, &proofs, &ts).unwrap());
+
+    // The streaming proof takes the same tags
+    let bytes: Vec<[u8; BYTES_PER_FIELD_ELEMENT]> =
+        blobs[0].iter().map(|field| field.to_bytes()).collect();
+    let streamed = compute_blob_kzg_proof_streaming_with_domains_rust(
+        || bytes.iter().copied(),
+        &commitments[0],
+        &ts,
+        &custom,
+    )
+    .unwrap();
+    assert!(streamed.equals(&proofs[0]));
+    assert!(verify_blob_kzg_proof_with_domains_rust(
+        &blobs[0],
+        &commitments[0],
+        &streamed,
+        &ts,
+        &custom
+    )
+    .unwrap());
+
+    // So does the aggregate proof
+    let aggregate =
+        compute_aggregate_kzg_proof_with_domains_rust(&blobs, &commitments, &ts, &custom).unwrap();
+    assert!(verify_aggregate_kzg_proof_with_domains_rust(
+        &blobs,
+        &commitments,
+        &aggregate,
+        &ts,
+        &custom
+    )
+    .unwrap());
+    assert!(!verify_aggregate_kzg_proof_rust(&blobs, &commitments, &aggregate, &ts).unwrap());
+
+    // Only the batch tag differs: the proofs are valid and so is the batch
+    let batch_only = DomainConfig {
+        random_challenge_kzg_batch: custom.random_challenge_kzg_batch,
+        ..DomainConfig::SPEC
+    };
+    let spec_proofs: Vec<TG1> = blobs
+        .iter()
+        .zip(&commitments)
+        .map(|(blob, commitment)| compute_blob_kzg_proof_rust(blob, commitment, &ts).unwrap())
+        .collect();
+    assert!(verify_blob_kzg_proof_batch_with_domains_rust(
+        &blobs,
+        &commitments,
+        &spec_proofs,
+        &ts,
+        &batch_only
+    )
+    .unwrap());
+
+    // The batch tag also seeds the batches of plain openings
+    let zs: Vec<TFr> = (0..2).map(|_| TFr::rand()).collect();
+    let (open_proofs, ys): (Vec<TG1>, Vec<TFr>) = blobs
+        .iter()
+        .zip(&zs)
+        .map(|(blob, z)| compute_kzg_proof_rust(blob, z, &ts).unwrap())
+        .unzip();
+    assert!(verify_kzg_proof_batch_with_domains_rust(
+        &commitments,
+        &zs,
+        &ys,
+        &open_proofs,
+        &ts,
+        &custom
+    )
+    .unwrap());
+    let (same_proofs, same_ys): (Vec<TG1>, Vec<TFr>) = zs
+        .iter()
+        .map(|z| compute_kzg_proof_rust(&blobs[0], z, &ts).unwrap())
+        .unzip();
+    assert!(verify_kzg_proof_batch_same_commitment_with_domains_rust(
+        &commitments[0],
+        &zs,
+        &same_ys,
+        &same_proofs,
+        &ts,
+        &custom
+    )
+    .unwrap());
+}
+
+pub fn evaluate_blob_in_evaluation_form_test<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+
+    let blob: Vec<TFr> = bytes_to_blob(&generate_random_blob_bytes(&mut rng)).unwrap();
+    let polynomial: TPoly = blob_to_polynomial(&blob).unwrap();
+
+    // Evaluating the blob in place gives the same value as evaluating its polynomial
+    let z = TFr::rand();
+    let y = evaluate_blob_in_evaluation_form(&blob, &z, &ts).unwrap();
+    assert!(y.equals(&evaluate_polynomial_in_evaluation_form(&polynomial, &z, &ts).unwrap()));
+
+    // Inside the domain the evaluation is the blob element itself
+    let root = ts.get_fft_settings().get_roots_of_unity()[7];
+    let y = evaluate_blob_in_evaluation_form(&blob, &root, &ts).unwrap();
+    assert!(y.equals(&blob[7]));
+
+    assert!(evaluate_blob_in_evaluation_form(&blob[1..], &z, &ts).is_err());
+}
+
+pub fn build_blob_sidecars_test<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine> + Sync,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+
+    let blobs: Vec<Vec<TFr>> = (0..3)
+        .map(|_| bytes_to_blob(&generate_random_blob_bytes(&mut rng)).unwrap())
+        .collect();
+    let commitments: Vec<TG1> = blobs
+        .iter()
+        .map(|blob| blob_to_kzg_commitment_rust(blob, &ts).unwrap())
+        .collect();
+
+    let sidecars = build_blob_sidecars(blobs.clone(), &commitments, &ts).unwrap();
+    assert_eq!(sidecars.len(), blobs.len());
+    for (i, sidecar) in sidecars.iter().enumerate() {
+        assert_eq!(sidecar.index, i as u64);
+        assert!(*sidecar.blob == *blobs[i]);
+        assert!(sidecar.kzg_commitment.equals(&commitments[i]));
+        assert_eq!(
+            sidecar.versioned_hash,
+            kzg_to_versioned_hash(&commitments[i])
+        );
+        assert!(verify_blob_kzg_proof_rust(
+            &sidecar.blob,
+            &sidecar.kzg_commitment,
+            &sidecar.kzg_proof,
+            &ts
+        )
+        .unwrap());
+    }
+
+    assert!(build_blob_sidecars(blobs.clone(), &commitments[1..], &ts).is_err());
+    let mut short_blobs = blobs;
+    short_blobs[2].pop();
+    assert!(build_blob_sidecars(short_blobs, &commitments, &ts).is_err());
+}
+
+pub fn verify_blob_bundle_test<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine> + PairingVerify<TG1, TG2>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine> + Sync,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+
+    let blobs: Vec<Vec<TFr>> = (0..3)
+        .map(|_| bytes_to_blob(&generate_random_blob_bytes(&mut rng)).unwrap())
+        .collect();
+    let commitments: Vec<TG1> = blobs
+        .iter()
+        .map(|blob| blob_to_kzg_commitment_rust(blob, &ts).unwrap())
+        .collect();
+    let proofs: Vec<TG1> = blobs
+        .iter()
+        .zip(&commitments)
+        .map(|(blob, commitment)| compute_blob_kzg_proof_rust(blob, commitment, &ts).unwrap())
+        .collect();
+    let versioned_hashes = kzg_to_versioned_hashes(&commitments);
+
+    assert!(
+        verify_blob_bundle_rust(&blobs, &commitments, &proofs, &versioned_hashes, &ts).unwrap()
+    );
+
+    // Versioned hashes listed in a different order than the blobs
+    let mut swapped = versioned_hashes.clone();
+    swapped.swap(0, 1);
+    assert!(!verify_blob_bundle_rust(&blobs, &commitments, &proofs, &swapped, &ts).unwrap());
+
+    // A valid blob, commitment and proof that no transaction refers to
+    let mut foreign = versioned_hashes.clone();
+    foreign[2][31] ^= 1;
+    assert!(!verify_blob_bundle_rust(&blobs, &commitments, &proofs, &foreign, &ts).unwrap());
+
+    // Hashes match, but a proof does not
+    let mut bad_proofs = proofs.clone();
+    bad_proofs.swap(1, 2);
+    assert!(
+        !verify_blob_bundle_rust(&blobs, &commitments, &bad_proofs, &versioned_hashes, &ts)
+            .unwrap()
+    );
+
+    assert!(
+        verify_blob_bundle_rust(&blobs, &commitments, &proofs, &versioned_hashes[..2], &ts)
+            .is_err()
+    );
+    assert!(
+        verify_blob_bundle_rust(&blobs[..2], &commitments, &proofs, &versioned_hashes, &ts)
+            .is_err()
+    );
+}
+
+pub fn blob_newtype_batch_apis_test<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine> + PairingVerify<TG1, TG2>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine> + Sync,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+
+    let blobs: Vec<Blob<TFr>> = (0..3)
+        .map(|_| Blob::from_bytes(&generate_random_blob_bytes(&mut rng)).unwrap())
+        .collect();
+
+    let commitments = blob_to_kzg_commitment_batch_rust(&blobs, &ts).unwrap();
+    let proofs: Vec<TG1> = blobs
+        .iter()
+        .zip(&commitments)
+        .map(|(blob, commitment)| compute_blob_kzg_proof_rust(blob, commitment, &ts).unwrap())
+        .collect();
+    assert!(verify_blob_kzg_proof_batch_rust(&blobs, &commitments, &proofs, &ts).unwrap());
+
+    let versioned_hashes = kzg_to_versioned_hashes(&commitments);
+    assert!(
+        verify_blob_bundle_rust(&blobs, &commitments, &proofs, &versioned_hashes, &ts).unwrap()
+    );
+
+    let aggregate = compute_aggregate_kzg_proof_rust(&blobs, &commitments, &ts).unwrap();
+    assert!(verify_aggregate_kzg_proof_rust(&blobs, &commitments, &aggregate, &ts).unwrap());
+
+    let sidecars = build_blob_sidecars(blobs.clone(), &commitments, &ts).unwrap();
+    assert!(sidecars
+        .iter()
+        .zip(&blobs)
+        .all(|(sidecar, blob)| sidecar.blob == *blob));
+}
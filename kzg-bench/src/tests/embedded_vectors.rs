@@ -0,0 +1,92 @@
+use crate::tests::utils::get_trusted_setup_path;
+use kzg::digest::DefaultDigest;
+use kzg::eip_4844::{
+    blob_to_kzg_commitment_rust, compute_blob_kzg_proof_rust, compute_kzg_proof_rust,
+    compute_r_powers_with_digest, BYTES_PER_FIELD_ELEMENT, FIELD_ELEMENTS_PER_BLOB,
+};
+use kzg::{
+    FFTSettings, Fr, G1Affine, G1Fp, G1GetFp, G1LinComb, G1Mul, KZGSettings, PairingVerify, Poly,
+    G1, G2,
+};
+
+/// Evaluation point used for the `compute_kzg_proof` vectors.
+const Z: u64 = 0x4844;
+
+const COMMITMENT: &str = "8ac148cadfc6192f745d6dfbe56c4624a55eb7f376efbe8cf4252fbaad09f6471ed414fba6314d2e4449f7597d52f7fb";
+const KZG_PROOF: &str = "938e807d49d3882e6db073600839e594a5f6bce071c1af793e94e3c9840df30d87c353a88e6c4df50afb602bcf60e5df";
+const KZG_PROOF_Y: &str = "1f290557e79ef6633e90c6612aa451f7879f16a7446287838ee3d53ce7aa2cd6";
+const BLOB_PROOF: &str = "a5f5ee1196d41640632097fdcb4bebd5ed8e294b44df8acc842163c7a9b7e2f1ce6688cfa80f0ef840453232628c5c9b";
+const BATCH_CHALLENGE: &str = "24e70e2e0212d6ecc1a6f8d9a547eea077845d709f2136b74ea5b9db15ee33ae";
+
+/// Blob whose i-th field element is `i * 0x9e3779b97f4a7c15` modulo 2^64.
+fn fixed_blob<TFr: Fr>() -> Vec<TFr> {
+    (0..FIELD_ELEMENTS_PER_BLOB)
+        .map(|i| {
+            let mut bytes = [0u8; BYTES_PER_FIELD_ELEMENT];
+            let value = (i as u64).wrapping_mul(0x9e3779b97f4a7c15);
+            bytes[BYTES_PER_FIELD_ELEMENT - 8..].copy_from_slice(&value.to_be_bytes());
+            TFr::from_bytes(&bytes).unwrap()
+        })
+        .collect()
+}
+
+/// Checks the EIP-4844 flows against known answers. Unlike a round trip, this fails when the prover
+/// and verifier change together.
+#[allow(clippy::type_complexity)]
+pub fn eip_4844_embedded_vectors_test<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine> + PairingVerify<TG1, TG2>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let blob = fixed_blob::<TFr>();
+
+    let commitment = blob_to_kzg_commitment_rust(&blob, &ts).unwrap();
+    assert_eq!(
+        hex::encode(commitment.to_bytes()),
+        COMMITMENT,
+        "commitment vector mismatch"
+    );
+
+    let z = TFr::from_u64(Z);
+    let (proof, y) = compute_kzg_proof_rust(&blob, &z, &ts).unwrap();
+    assert_eq!(
+        hex::encode(proof.to_bytes()),
+        KZG_PROOF,
+        "kzg proof vector mismatch"
+    );
+    assert_eq!(
+        hex::encode(y.to_bytes()),
+        KZG_PROOF_Y,
+        "kzg proof y vector mismatch"
+    );
+
+    let blob_proof = compute_blob_kzg_proof_rust(&blob, &commitment, &ts).unwrap();
+    assert_eq!(
+        hex::encode(blob_proof.to_bytes()),
+        BLOB_PROOF,
+        "blob proof vector mismatch"
+    );
+
+    // Batch challenge over the opening at z and the generator opening to zero at zero
+    let r_powers = compute_r_powers_with_digest::<TG1, TFr, DefaultDigest>(
+        &[commitment, TG1::generator()],
+        &[z, TFr::zero()],
+        &[y, TFr::zero()],
+        &[proof, TG1::generator()],
+        false,
+    )
+    .unwrap();
+    assert_eq!(
+        hex::encode(r_powers[1].to_bytes()),
+        BATCH_CHALLENGE,
+        "batch challenge vector mismatch"
+    );
+}
@@ -132,6 +132,55 @@ pub fn commit_to_too_long_poly_returns_err<
     assert!(_result.is_err());
 }
 
+pub fn commit_monomial_degree_bound<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    generate_trusted_setup: &dyn Fn(usize, [u8; 32usize]) -> (Vec<TG1>, Vec<TG2>),
+) {
+    let secrets_len = 16;
+    let (s1, s2) = generate_trusted_setup(secrets_len, SECRET);
+    let fs = TFFTSettings::new(4).unwrap();
+    let ks = TKZGSettings::new(&s1, &s2, secrets_len, &fs).unwrap();
+
+    let coeffs: Vec<TFr> = (0..secrets_len as u64 + 1)
+        .map(|i| TFr::from_u64(i + 1))
+        .collect();
+
+    // Exactly as many coefficients as setup points is the largest polynomial that fits
+    let p = TPoly::from_coeffs(&coeffs[..secrets_len]);
+    let commitment = ks.commit_monomial(&coeffs[..secrets_len]).unwrap();
+    assert!(commitment.equals(&ks.commit_to_poly(&p).unwrap()));
+    let x = TFr::from_u64(25);
+    let proof = ks.compute_proof_single(&p, &x).unwrap();
+    assert!(ks
+        .check_proof_single(&commitment, &proof, &x, &p.eval(&x))
+        .unwrap());
+
+    // One more coefficient is rejected on every path, naming both lengths
+    let expected_err = format!(
+        "Polynomial has {} coefficients, but the setup only supports {}",
+        secrets_len + 1,
+        secrets_len
+    );
+    let too_long = TPoly::from_coeffs(&coeffs);
+    assert_eq!(ks.commit_monomial(&coeffs), Err(expected_err.clone()));
+    assert_eq!(ks.commit_to_poly(&too_long), Err(expected_err.clone()));
+    assert_eq!(
+        ks.compute_proof_single(&too_long, &x),
+        Err(expected_err.clone())
+    );
+    assert_eq!(ks.compute_proof_multi(&too_long, &x, 2), Err(expected_err));
+
+    assert!(ks.commit_monomial(&[]).unwrap().is_inf());
+}
+
 //It was not verified that this test works, use with caution
 pub fn proof_multi<
     TFr: Fr,
@@ -201,3 +250,87 @@ pub fn proof_multi<
         .unwrap();
     assert!(!result);
 }
+
+pub fn with_shared_points_settings<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    generate_trusted_setup: &dyn Fn(usize, [u8; 32usize]) -> (Vec<TG1>, Vec<TG2>),
+) {
+    let coeffs = [1, 2, 3, 4, 7, 7, 7, 7, 13, 13, 13, 13, 13, 13, 13, 13];
+    let poly_len = coeffs.len();
+    let secrets_len = poly_len + 1;
+
+    let mut p = TPoly::new(poly_len);
+    for (x, &coeff) in coeffs.iter().enumerate() {
+        p.set_coeff_at(x, &TFr::from_u64(coeff));
+    }
+
+    let (s1, s2) = generate_trusted_setup(secrets_len, SECRET);
+    let fs = TFFTSettings::new(4).unwrap();
+    let base = TKZGSettings::new(&s1, &s2, secrets_len, &fs).unwrap();
+
+    let x = TFr::from_u64(25);
+    let commitment = base.commit_to_poly(&p).unwrap();
+    let proof = base.compute_proof_single(&p, &x).unwrap();
+    let value = p.eval(&x);
+
+    for with_precomputation in [false, true] {
+        let shared = TKZGSettings::with_shared_points(&base, with_precomputation).unwrap();
+
+        // Both settings must point at the very same setup storage
+        assert!(core::ptr::eq(
+            base.get_g1_secret().as_ptr(),
+            shared.get_g1_secret().as_ptr()
+        ));
+        assert_eq!(base.get_g1_secret().len(), shared.get_g1_secret().len());
+        assert!(core::ptr::eq(
+            base.get_g2_secret().as_ptr(),
+            shared.get_g2_secret().as_ptr()
+        ));
+        assert_eq!(base.get_g2_secret().len(), shared.get_g2_secret().len());
+
+        if !with_precomputation {
+            assert!(shared.get_precomputation().is_none());
+        } else if let Some(table) = base.get_precomputation() {
+            assert!(core::ptr::eq(table, shared.get_precomputation().unwrap()));
+        }
+
+        assert!(shared.commit_to_poly(&p).unwrap().equals(&commitment));
+        assert!(shared.compute_proof_single(&p, &x).unwrap().equals(&proof));
+        assert!(shared
+            .check_proof_single(&commitment, &proof, &x, &value)
+            .unwrap());
+    }
+}
+
+/// Check that the generator the verifiers take from the settings is the G2 generator
+pub fn get_g2_generator_matches_generator<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    generate_trusted_setup: &dyn Fn(usize, [u8; 32usize]) -> (Vec<TG1>, Vec<TG2>),
+) {
+    let secrets_len = 17;
+
+    let (s1, s2) = generate_trusted_setup(secrets_len, SECRET);
+    let fs = TFFTSettings::new(4).unwrap();
+    let ks = TKZGSettings::new(&s1, &s2, secrets_len, &fs).unwrap();
+
+    let generator = ks.get_g2_generator();
+    assert!(generator.equals(&TG2::generator()));
+    // The setup starts with the secret to the power of zero
+    assert!(generator.equals(&ks.get_g2_secret()[0]));
+}
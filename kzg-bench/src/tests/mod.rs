@@ -1,8 +1,12 @@
 pub mod bls12_381;
 pub mod c_bindings;
+pub mod consistency;
 pub mod consts;
+#[cfg(feature = "counting")]
+pub mod counting;
 pub mod das;
 pub mod eip_4844;
+pub mod embedded_vectors;
 pub mod fft_fr;
 pub mod fft_g1;
 pub mod finite;
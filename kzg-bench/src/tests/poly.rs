@@ -372,3 +372,107 @@ pub fn poly_div_fast_test<TFr: Fr, TPoly: Poly<TFr>>() {
         }
     }
 }
+
+fn random_poly<TFr: Fr, TPoly: Poly<TFr>>(len: usize) -> TPoly {
+    let mut poly = TPoly::new(len);
+    for i in 0..len {
+        poly.set_coeff_at(i, &TFr::rand());
+    }
+
+    //Ensure that the polynomial's order corresponds to its length
+    if poly.get_coeff_at(len - 1).is_zero() {
+        poly.set_coeff_at(len - 1, &TFr::one());
+    }
+
+    poly
+}
+
+fn coeff_or_zero<TFr: Fr, TPoly: Poly<TFr>>(poly: &TPoly, i: usize) -> TFr {
+    if i < poly.len() {
+        poly.get_coeff_at(i)
+    } else {
+        TFr::zero()
+    }
+}
+
+pub fn poly_mul_div_rem_round_trip<TFr: Fr, TPoly: Poly<TFr>>() {
+    let mut rng = StdRng::seed_from_u64(0);
+    for _k in 0..32 {
+        let a_length: usize = (1 + (rng.next_u64() % 300)) as usize;
+        let b_length: usize = (1 + (rng.next_u64() % 300)) as usize;
+
+        let mut a: TPoly = random_poly(a_length);
+        let b: TPoly = random_poly(b_length);
+
+        // Large enough inputs take the FFT multiplication and fast division paths
+        let mut product = a.mul(&b, a_length + b_length - 1).unwrap();
+        let (quotient, remainder) = product.div_rem(&b).unwrap();
+
+        for i in 0..quotient.len().max(a_length) {
+            assert!(coeff_or_zero(&quotient, i).equals(&coeff_or_zero(&a, i)));
+        }
+
+        assert_eq!(remainder.len(), b_length - 1);
+        for i in 0..remainder.len() {
+            assert!(remainder.get_coeff_at(i).is_zero());
+        }
+    }
+}
+
+pub fn poly_div_rem_random<TFr: Fr, TPoly: Poly<TFr>>() {
+    let mut rng = StdRng::seed_from_u64(0);
+    for _k in 0..32 {
+        let dividend_length: usize = (1 + (rng.next_u64() % 300)) as usize;
+        let divisor_length: usize = (1 + (rng.next_u64() % 300)) as usize;
+
+        let mut dividend: TPoly = random_poly(dividend_length);
+        let divisor: TPoly = random_poly(divisor_length);
+
+        let (mut quotient, remainder) = dividend.div_rem(&divisor).unwrap();
+
+        // deg(remainder) < deg(divisor)
+        assert_eq!(remainder.len(), divisor_length - 1);
+
+        // dividend == quotient * divisor + remainder
+        let product = if quotient.is_empty() {
+            TPoly::new(0)
+        } else {
+            quotient.mul_direct(&divisor, dividend_length).unwrap()
+        };
+        for i in 0..dividend_length {
+            let actual = coeff_or_zero(&product, i).add(&coeff_or_zero(&remainder, i));
+            assert!(actual.equals(&dividend.get_coeff_at(i)));
+        }
+    }
+}
+
+pub fn poly_div_rem_by_zero<TFr: Fr, TPoly: Poly<TFr>>() {
+    let mut dividend: TPoly = new_test_poly(&[1, 1]);
+
+    assert!(dividend.div_rem(&TPoly::new(0)).is_err());
+    assert!(dividend.div_rem(&new_test_poly(&[1, 0])).is_err());
+}
+
+pub fn poly_pad_and_scale_by<TFr: Fr, TPoly: Poly<TFr>>() {
+    let poly: TPoly = new_test_poly(&[1, 2, 3]);
+
+    let padded = poly.pad(5);
+    assert_eq!(padded.len(), 5);
+    for i in 0..3 {
+        assert!(padded.get_coeff_at(i).equals(&poly.get_coeff_at(i)));
+    }
+    assert!(padded.get_coeff_at(3).is_zero());
+    assert!(padded.get_coeff_at(4).is_zero());
+
+    let truncated = poly.pad(2);
+    assert_eq!(truncated.len(), 2);
+    assert!(truncated.get_coeff_at(1).equals(&poly.get_coeff_at(1)));
+
+    let factor = TFr::from_u64(7);
+    let scaled = poly.scale_by(&factor);
+    let expected: TPoly = new_test_poly(&[7, 14, 21]);
+    assert_eq!(scaled.len(), 3);
+    for i in 0..3 {
+        assert!(scaled.get_coeff_at(i).equals(&expected.get_coeff_at(i)));
+    }
+}
@@ -0,0 +1,75 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Deref;
+
+use crate::eip_4844::{bytes_to_blob, BYTES_PER_BLOB, FIELD_ELEMENTS_PER_BLOB};
+use crate::{Fr, Poly};
+
+/// Exactly [`FIELD_ELEMENTS_PER_BLOB`] field elements. Dereferences to `[TFr]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Blob<TFr>(Vec<TFr>);
+
+impl<TFr: Fr> Blob<TFr> {
+    pub fn new(elements: Vec<TFr>) -> Result<Self, String> {
+        if elements.len() != FIELD_ELEMENTS_PER_BLOB {
+            return Err(format!(
+                "Blob length must be FIELD_ELEMENTS_PER_BLOB ({}), got {}",
+                FIELD_ELEMENTS_PER_BLOB,
+                elements.len()
+            ));
+        }
+
+        Ok(Self(elements))
+    }
+
+    /// Parses a serialized blob, rejecting any element that is not a canonical field element.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        bytes_to_blob(bytes).map(Self)
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(BYTES_PER_BLOB);
+        for element in &self.0 {
+            bytes.extend_from_slice(&element.to_bytes());
+        }
+        bytes
+    }
+
+    /// The blob as a polynomial in evaluation form, with one evaluation per element.
+    pub fn as_polynomial<TPoly: Poly<TFr>>(&self) -> TPoly {
+        TPoly::from_coeffs(&self.0)
+    }
+
+    pub fn into_inner(self) -> Vec<TFr> {
+        self.0
+    }
+}
+
+impl<TFr> Deref for Blob<TFr> {
+    type Target = [TFr];
+
+    fn deref(&self) -> &[TFr] {
+        &self.0
+    }
+}
+
+impl<TFr> AsRef<[TFr]> for Blob<TFr> {
+    fn as_ref(&self) -> &[TFr] {
+        &self.0
+    }
+}
+
+impl<TFr> From<Blob<TFr>> for Vec<TFr> {
+    fn from(blob: Blob<TFr>) -> Vec<TFr> {
+        blob.0
+    }
+}
+
+impl<TFr: Fr> TryFrom<Vec<TFr>> for Blob<TFr> {
+    type Error = String;
+
+    fn try_from(elements: Vec<TFr>) -> Result<Self, String> {
+        Self::new(elements)
+    }
+}
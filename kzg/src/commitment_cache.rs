@@ -0,0 +1,151 @@
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+
+use crate::eip_4844::{
+    verify_blob_kzg_proof_unchecked, DomainConfig, BYTES_PER_COMMITMENT, BYTES_PER_PROOF,
+};
+use crate::{FFTSettings, Fr, G1Affine, G1Fp, G1GetFp, G1Mul, KZGSettings, Poly, G1, G2};
+
+/// Least recently used cache of at most `capacity` validated commitments, keyed by their bytes.
+/// A hit skips both decompression and the subgroup check.
+#[derive(Debug, Clone)]
+pub struct CommitmentCache<TG1> {
+    capacity: usize,
+    tick: u64,
+    hits: u64,
+    misses: u64,
+    entries: BTreeMap<[u8; BYTES_PER_COMMITMENT], (TG1, u64)>,
+    recency: BTreeMap<u64, [u8; BYTES_PER_COMMITMENT]>,
+}
+
+impl<TG1: G1> CommitmentCache<TG1> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            tick: 0,
+            hits: 0,
+            misses: 0,
+            entries: BTreeMap::new(),
+            recency: BTreeMap::new(),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Changes the capacity, evicting the least recently used entries that no longer fit.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        while self.entries.len() > capacity {
+            self.evict_oldest();
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+    }
+
+    /// Returns the cached point for `bytes`, marking it as most recently used.
+    pub fn get(&mut self, bytes: &[u8; BYTES_PER_COMMITMENT]) -> Option<TG1> {
+        let tick = self.next_tick();
+        match self.entries.get_mut(bytes) {
+            Some((point, last_used)) => {
+                self.recency.remove(last_used);
+                self.recency.insert(tick, *bytes);
+                *last_used = tick;
+                self.hits += 1;
+                Some(point.clone())
+            }
+            None => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    /// Deserializes and validates `bytes`, then caches the point. Invalid bytes return an error
+    /// and leave the cache untouched.
+    pub fn insert(&mut self, bytes: &[u8; BYTES_PER_COMMITMENT]) -> Result<TG1, String> {
+        let point = TG1::from_bytes(bytes)?;
+        if !point.is_inf() && !point.is_valid() {
+            return Err("Invalid commitment".to_string());
+        }
+
+        if self.capacity == 0 {
+            return Ok(point);
+        }
+
+        let tick = self.next_tick();
+        if let Some((_, last_used)) = self.entries.insert(*bytes, (point.clone(), tick)) {
+            self.recency.remove(&last_used);
+        } else if self.entries.len() > self.capacity {
+            self.evict_oldest();
+        }
+        self.recency.insert(tick, *bytes);
+
+        Ok(point)
+    }
+
+    pub fn get_or_insert(&mut self, bytes: &[u8; BYTES_PER_COMMITMENT]) -> Result<TG1, String> {
+        match self.get(bytes) {
+            Some(point) => Ok(point),
+            None => self.insert(bytes),
+        }
+    }
+
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+
+    fn evict_oldest(&mut self) {
+        if let Some((_, bytes)) = self.recency.pop_first() {
+            self.entries.remove(&bytes);
+        }
+    }
+}
+
+/// Same as [`crate::eip_4844::verify_blob_kzg_proof_rust`], but takes the commitment and proof as
+/// bytes and looks the commitment up in `cache` before deserializing it.
+pub fn verify_blob_kzg_proof_cached_rust<
+    TFr: Fr + Copy,
+    TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    blob: &[TFr],
+    commitment_bytes: &[u8; BYTES_PER_COMMITMENT],
+    proof_bytes: &[u8; BYTES_PER_PROOF],
+    ts: &TKZGSettings,
+    cache: &mut CommitmentCache<TG1>,
+) -> Result<bool, String> {
+    let commitment_g1 = cache.get_or_insert(commitment_bytes)?;
+    let proof_g1 = TG1::from_bytes(proof_bytes)?;
+    if !proof_g1.is_inf() && !proof_g1.is_valid() {
+        return Err("Invalid proof".to_string());
+    }
+
+    verify_blob_kzg_proof_unchecked(blob, &commitment_g1, &proof_g1, ts, &DomainConfig::SPEC)
+}
@@ -1,8 +1,24 @@
 extern crate alloc;
 
+use alloc::format;
 use alloc::string::String;
+use alloc::vec::Vec;
 use core::mem;
 
+use crate::digest::{DefaultDigest, Digest, DIGEST_BYTES};
+
+// Committing to more coefficients than there are setup points would silently drop the excess
+pub fn check_degree_bound(len: usize, max_len: usize) -> Result<(), String> {
+    if len > max_len {
+        return Err(format!(
+            "Polynomial has {} coefficients, but the setup only supports {}",
+            len, max_len
+        ));
+    }
+
+    Ok(())
+}
+
 pub fn reverse_bit_order<T>(vals: &mut [T]) -> Result<(), String>
 where
     T: Clone,
@@ -17,7 +33,10 @@ where
     }
 
     if !vals.len().is_power_of_two() {
-        return Err(String::from("Values length has to be a power of 2"));
+        return Err(format!(
+            "Values length has to be a power of 2, got {}",
+            vals.len()
+        ));
     }
 
     let unused_bit_len = vals.len().leading_zeros() + 1;
@@ -95,3 +114,67 @@ pub fn reverse_bits_limited(length: usize, value: usize) -> usize {
     let unused_bits = length.leading_zeros();
     value.reverse_bits() >> unused_bits
 }
+
+/// `expand_message_xmd` from RFC 9380, section 5.3.1, instantiated with SHA-256.
+pub fn expand_message_xmd(msg: &[u8], dst: &[u8], len_in_bytes: usize) -> Result<Vec<u8>, String> {
+    const B_IN_BYTES: usize = DIGEST_BYTES;
+    const S_IN_BYTES: usize = 64;
+
+    let ell = len_in_bytes.div_ceil(B_IN_BYTES);
+    if ell > 255 || len_in_bytes > u16::MAX as usize {
+        return Err(format!(
+            "Requested output length is too large, got {}",
+            len_in_bytes
+        ));
+    }
+
+    // Tags longer than 255 bytes are hashed down first, see RFC 9380 section 5.3.3
+    let oversize_dst;
+    let dst = if dst.len() > 255 {
+        oversize_dst = DefaultDigest::default()
+            .chain_update(b"H2C-OVERSIZE-DST-")
+            .chain_update(dst)
+            .finalize();
+        &oversize_dst[..]
+    } else {
+        dst
+    };
+    let dst_len = [dst.len() as u8];
+
+    let b_0 = DefaultDigest::default()
+        .chain_update(&[0u8; S_IN_BYTES])
+        .chain_update(msg)
+        .chain_update(&(len_in_bytes as u16).to_be_bytes())
+        .chain_update(&[0u8])
+        .chain_update(dst)
+        .chain_update(&dst_len)
+        .finalize();
+
+    let mut b_i = DefaultDigest::default()
+        .chain_update(&b_0)
+        .chain_update(&[1u8])
+        .chain_update(dst)
+        .chain_update(&dst_len)
+        .finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * B_IN_BYTES);
+    uniform_bytes.extend_from_slice(&b_i);
+
+    for i in 2..=ell {
+        let mut xored = [0u8; B_IN_BYTES];
+        for ((x, a), b) in xored.iter_mut().zip(b_0.iter()).zip(b_i.iter()) {
+            *x = a ^ b;
+        }
+
+        b_i = DefaultDigest::default()
+            .chain_update(&xored)
+            .chain_update(&[i as u8])
+            .chain_update(dst)
+            .chain_update(&dst_len)
+            .finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+
+    uniform_bytes.truncate(len_in_bytes);
+    Ok(uniform_bytes)
+}
@@ -0,0 +1,665 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cell::Cell;
+use core::ops::Sub;
+
+use crate::msm::precompute::PrecomputationTable;
+use crate::{
+    FFTFr, FFTSettings, Fr, G1Affine, G1Fp, G1GetFp, G1LinComb, G1Mul, KZGSettings, PairingVerify,
+    Poly, Scalar256, DAS, G1, G2,
+};
+
+/// Number of operations performed on the current thread, per kind.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct OpCounts {
+    /// Additions and subtractions
+    pub fr_add: u64,
+    pub fr_mul: u64,
+    pub fr_sqr: u64,
+    /// Inversions and divisions
+    pub fr_inverse: u64,
+    pub fr_pow: u64,
+    /// Additions and subtractions, including those that may double
+    pub g1_add: u64,
+    pub g1_dbl: u64,
+    /// Scalar multiplications
+    pub g1_mul: u64,
+    /// Multi-scalar multiplications, each counted once
+    pub g1_lincomb: u64,
+    /// Points summed over all multi-scalar multiplications
+    pub g1_lincomb_points: u64,
+    /// FFTs and DAS extensions, each counted once
+    pub fft: u64,
+    /// Pairing checks, each counted once
+    pub pairing_checks: u64,
+}
+
+impl OpCounts {
+    pub fn snapshot() -> Self {
+        COUNTS.with(|counts| counts.get())
+    }
+
+    pub fn reset() {
+        COUNTS.with(|counts| counts.set(Self::default()));
+    }
+}
+
+// Saturates so that a snapshot taken before a `reset` can't underflow
+impl Sub for OpCounts {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            fr_add: self.fr_add.saturating_sub(rhs.fr_add),
+            fr_mul: self.fr_mul.saturating_sub(rhs.fr_mul),
+            fr_sqr: self.fr_sqr.saturating_sub(rhs.fr_sqr),
+            fr_inverse: self.fr_inverse.saturating_sub(rhs.fr_inverse),
+            fr_pow: self.fr_pow.saturating_sub(rhs.fr_pow),
+            g1_add: self.g1_add.saturating_sub(rhs.g1_add),
+            g1_dbl: self.g1_dbl.saturating_sub(rhs.g1_dbl),
+            g1_mul: self.g1_mul.saturating_sub(rhs.g1_mul),
+            g1_lincomb: self.g1_lincomb.saturating_sub(rhs.g1_lincomb),
+            g1_lincomb_points: self.g1_lincomb_points.saturating_sub(rhs.g1_lincomb_points),
+            fft: self.fft.saturating_sub(rhs.fft),
+            pairing_checks: self.pairing_checks.saturating_sub(rhs.pairing_checks),
+        }
+    }
+}
+
+std::thread_local! {
+    static COUNTS: Cell<OpCounts> = Cell::new(OpCounts::default());
+}
+
+fn bump(op: impl FnOnce(&mut OpCounts)) {
+    COUNTS.with(|counts| {
+        let mut current = counts.get();
+        op(&mut current);
+        counts.set(current);
+    });
+}
+
+/// Runs `f` and returns its result with the operations it performed on the current thread.
+pub fn count_ops<R>(f: impl FnOnce() -> R) -> (R, OpCounts) {
+    let before = OpCounts::snapshot();
+    let result = f();
+    (result, OpCounts::snapshot() - before)
+}
+
+/// Instrumented wrapper around a backend's types. Work spread over a thread pool is
+/// undercounted, see [`count_ops`].
+///
+/// Wrapped settings and polynomials delegate to the backend, so only the arithmetic that generic
+/// code performs through the wrapped types is counted. MSMs, FFTs and pairing checks are counted
+/// per call, since their inner operations run inside the backend.
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Counting<T>(pub T);
+
+// `Counting` is `repr(transparent)`, so `T` and `Counting<T>` share a layout and slices or
+// references of one can be reborrowed as the other
+
+fn wrap_ref<T>(value: &T) -> &Counting<T> {
+    unsafe { &*(value as *const T).cast::<Counting<T>>() }
+}
+
+fn wrap_slice<T>(slice: &[T]) -> &[Counting<T>] {
+    unsafe { core::slice::from_raw_parts(slice.as_ptr().cast(), slice.len()) }
+}
+
+fn unwrap_slice<T>(slice: &[Counting<T>]) -> &[T] {
+    unsafe { core::slice::from_raw_parts(slice.as_ptr().cast(), slice.len()) }
+}
+
+fn unwrap_slice_mut<T>(slice: &mut [Counting<T>]) -> &mut [T] {
+    unsafe { core::slice::from_raw_parts_mut(slice.as_mut_ptr().cast(), slice.len()) }
+}
+
+fn wrap_vec<T>(vec: Vec<T>) -> Vec<Counting<T>> {
+    vec.into_iter().map(Counting).collect()
+}
+
+impl<TFr: Fr> Fr for Counting<TFr> {
+    fn null() -> Self {
+        Self(TFr::null())
+    }
+
+    fn zero() -> Self {
+        Self(TFr::zero())
+    }
+
+    fn one() -> Self {
+        Self(TFr::one())
+    }
+
+    #[cfg(feature = "rand")]
+    fn rand() -> Self {
+        Self(TFr::rand())
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        TFr::from_bytes(bytes).map(Self)
+    }
+
+    fn from_bytes_unchecked(bytes: &[u8]) -> Result<Self, String> {
+        TFr::from_bytes_unchecked(bytes).map(Self)
+    }
+
+    fn from_hex(hex: &str) -> Result<Self, String> {
+        TFr::from_hex(hex).map(Self)
+    }
+
+    fn from_u64_arr(u: &[u64; 4]) -> Self {
+        Self(TFr::from_u64_arr(u))
+    }
+
+    fn from_u64(u: u64) -> Self {
+        Self(TFr::from_u64(u))
+    }
+
+    fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_bytes()
+    }
+
+    fn to_u64_arr(&self) -> [u64; 4] {
+        self.0.to_u64_arr()
+    }
+
+    fn is_one(&self) -> bool {
+        self.0.is_one()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    fn is_null(&self) -> bool {
+        self.0.is_null()
+    }
+
+    fn sqr(&self) -> Self {
+        bump(|counts| counts.fr_sqr += 1);
+        Self(self.0.sqr())
+    }
+
+    fn mul(&self, b: &Self) -> Self {
+        bump(|counts| counts.fr_mul += 1);
+        Self(self.0.mul(&b.0))
+    }
+
+    fn add(&self, b: &Self) -> Self {
+        bump(|counts| counts.fr_add += 1);
+        Self(self.0.add(&b.0))
+    }
+
+    fn sub(&self, b: &Self) -> Self {
+        bump(|counts| counts.fr_add += 1);
+        Self(self.0.sub(&b.0))
+    }
+
+    fn eucl_inverse(&self) -> Self {
+        bump(|counts| counts.fr_inverse += 1);
+        Self(self.0.eucl_inverse())
+    }
+
+    fn negate(&self) -> Self {
+        Self(self.0.negate())
+    }
+
+    fn inverse(&self) -> Self {
+        bump(|counts| counts.fr_inverse += 1);
+        Self(self.0.inverse())
+    }
+
+    fn pow(&self, n: usize) -> Self {
+        bump(|counts| counts.fr_pow += 1);
+        Self(self.0.pow(n))
+    }
+
+    fn div(&self, b: &Self) -> Result<Self, String> {
+        bump(|counts| counts.fr_inverse += 1);
+        self.0.div(&b.0).map(Self)
+    }
+
+    fn equals(&self, b: &Self) -> bool {
+        self.0.equals(&b.0)
+    }
+
+    fn to_scalar(&self) -> Scalar256 {
+        self.0.to_scalar()
+    }
+}
+
+impl<TG1: G1> G1 for Counting<TG1> {
+    fn zero() -> Self {
+        Self(TG1::zero())
+    }
+
+    fn identity() -> Self {
+        Self(TG1::identity())
+    }
+
+    fn generator() -> Self {
+        Self(TG1::generator())
+    }
+
+    fn negative_generator() -> Self {
+        Self(TG1::negative_generator())
+    }
+
+    #[cfg(feature = "rand")]
+    fn rand() -> Self {
+        Self(TG1::rand())
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        TG1::from_bytes(bytes).map(Self)
+    }
+
+    fn from_hex(hex: &str) -> Result<Self, String> {
+        TG1::from_hex(hex).map(Self)
+    }
+
+    fn to_bytes(&self) -> [u8; 48] {
+        self.0.to_bytes()
+    }
+
+    fn add_or_dbl(&self, b: &Self) -> Self {
+        bump(|counts| counts.g1_add += 1);
+        Self(self.0.add_or_dbl(&b.0))
+    }
+
+    fn is_inf(&self) -> bool {
+        self.0.is_inf()
+    }
+
+    fn is_valid(&self) -> bool {
+        self.0.is_valid()
+    }
+
+    fn dbl(&self) -> Self {
+        bump(|counts| counts.g1_dbl += 1);
+        Self(self.0.dbl())
+    }
+
+    fn add(&self, b: &Self) -> Self {
+        bump(|counts| counts.g1_add += 1);
+        Self(self.0.add(&b.0))
+    }
+
+    fn sub(&self, b: &Self) -> Self {
+        bump(|counts| counts.g1_add += 1);
+        Self(self.0.sub(&b.0))
+    }
+
+    fn equals(&self, b: &Self) -> bool {
+        self.0.equals(&b.0)
+    }
+
+    fn add_or_dbl_assign(&mut self, b: &Self) {
+        bump(|counts| counts.g1_add += 1);
+        self.0.add_or_dbl_assign(&b.0);
+    }
+
+    fn add_assign(&mut self, b: &Self) {
+        bump(|counts| counts.g1_add += 1);
+        self.0.add_assign(&b.0);
+    }
+
+    fn dbl_assign(&mut self) {
+        bump(|counts| counts.g1_dbl += 1);
+        self.0.dbl_assign();
+    }
+}
+
+impl<TFr: Fr, TG1: G1Mul<TFr>> G1Mul<Counting<TFr>> for Counting<TG1> {
+    fn mul(&self, b: &Counting<TFr>) -> Self {
+        bump(|counts| counts.g1_mul += 1);
+        Self(self.0.mul(&b.0))
+    }
+}
+
+impl<TG1Fp: G1Fp, TG1: G1GetFp<TG1Fp>> G1GetFp<TG1Fp> for Counting<TG1> {
+    fn x(&self) -> &TG1Fp {
+        self.0.x()
+    }
+
+    fn y(&self) -> &TG1Fp {
+        self.0.y()
+    }
+
+    fn z(&self) -> &TG1Fp {
+        self.0.z()
+    }
+
+    fn x_mut(&mut self) -> &mut TG1Fp {
+        self.0.x_mut()
+    }
+
+    fn y_mut(&mut self) -> &mut TG1Fp {
+        self.0.y_mut()
+    }
+
+    fn z_mut(&mut self) -> &mut TG1Fp {
+        self.0.z_mut()
+    }
+}
+
+impl<TG1: G1, TG1Fp: G1Fp, TG1Affine: G1Affine<TG1, TG1Fp>> G1Affine<Counting<TG1>, TG1Fp>
+    for Counting<TG1Affine>
+{
+    fn zero() -> Self {
+        Self(TG1Affine::zero())
+    }
+
+    fn into_affine(g1: &Counting<TG1>) -> Self {
+        Self(TG1Affine::into_affine(&g1.0))
+    }
+
+    fn into_affines_loc(out: &mut [Self], g1: &[Counting<TG1>]) {
+        TG1Affine::into_affines_loc(unwrap_slice_mut(out), unwrap_slice(g1));
+    }
+
+    fn to_proj(&self) -> Counting<TG1> {
+        Counting(self.0.to_proj())
+    }
+
+    fn x(&self) -> &TG1Fp {
+        self.0.x()
+    }
+
+    fn y(&self) -> &TG1Fp {
+        self.0.y()
+    }
+
+    fn x_mut(&mut self) -> &mut TG1Fp {
+        self.0.x_mut()
+    }
+
+    fn y_mut(&mut self) -> &mut TG1Fp {
+        self.0.y_mut()
+    }
+
+    fn is_infinity(&self) -> bool {
+        self.0.is_infinity()
+    }
+}
+
+impl<
+        TFr: Fr,
+        TG1Fp: G1Fp,
+        TG1Affine: G1Affine<TG1, TG1Fp>,
+        TG1: G1LinComb<TFr, TG1Fp, TG1Affine>,
+    > G1LinComb<Counting<TFr>, TG1Fp, Counting<TG1Affine>> for Counting<TG1>
+{
+    // A table built for the wrapped types can't be handed to the backend, so the backend runs
+    // without one. That only changes how fast the result is computed.
+    fn g1_lincomb(
+        points: &[Self],
+        scalars: &[Counting<TFr>],
+        len: usize,
+        _precomputation: Option<
+            &PrecomputationTable<Counting<TFr>, Self, TG1Fp, Counting<TG1Affine>>,
+        >,
+    ) -> Self {
+        bump(|counts| {
+            counts.g1_lincomb += 1;
+            counts.g1_lincomb_points += len as u64;
+        });
+        Self(TG1::g1_lincomb(
+            unwrap_slice(points),
+            unwrap_slice(scalars),
+            len,
+            None,
+        ))
+    }
+}
+
+impl<TG1: G1 + PairingVerify<TG1, TG2>, TG2: G2> PairingVerify<Counting<TG1>, TG2>
+    for Counting<TG1>
+{
+    fn verify(a1: &Counting<TG1>, a2: &TG2, b1: &Counting<TG1>, b2: &TG2) -> bool {
+        bump(|counts| counts.pairing_checks += 1);
+        TG1::verify(&a1.0, a2, &b1.0, b2)
+    }
+}
+
+impl<TFr: Fr, TFFTSettings: FFTSettings<TFr>> FFTSettings<Counting<TFr>>
+    for Counting<TFFTSettings>
+{
+    fn new(scale: usize) -> Result<Self, String> {
+        TFFTSettings::new(scale).map(Self)
+    }
+
+    fn new_with_root(scale: usize, root_of_unity: &Counting<TFr>) -> Result<Self, String> {
+        TFFTSettings::new_with_root(scale, &root_of_unity.0).map(Self)
+    }
+
+    fn get_max_width(&self) -> usize {
+        self.0.get_max_width()
+    }
+
+    fn get_expanded_roots_of_unity_at(&self, i: usize) -> Counting<TFr> {
+        Counting(self.0.get_expanded_roots_of_unity_at(i))
+    }
+
+    fn get_expanded_roots_of_unity(&self) -> &[Counting<TFr>] {
+        wrap_slice(self.0.get_expanded_roots_of_unity())
+    }
+
+    fn get_reverse_roots_of_unity_at(&self, i: usize) -> Counting<TFr> {
+        Counting(self.0.get_reverse_roots_of_unity_at(i))
+    }
+
+    fn get_reversed_roots_of_unity(&self) -> &[Counting<TFr>] {
+        wrap_slice(self.0.get_reversed_roots_of_unity())
+    }
+
+    fn get_roots_of_unity_at(&self, i: usize) -> Counting<TFr> {
+        Counting(self.0.get_roots_of_unity_at(i))
+    }
+
+    fn try_get_roots_of_unity_at(&self, i: usize) -> Result<Counting<TFr>, String> {
+        self.0.try_get_roots_of_unity_at(i).map(Counting)
+    }
+
+    fn get_roots_of_unity(&self) -> &[Counting<TFr>] {
+        wrap_slice(self.0.get_roots_of_unity())
+    }
+}
+
+impl<TFr: Fr, TFFTSettings: FFTFr<TFr>> FFTFr<Counting<TFr>> for Counting<TFFTSettings> {
+    fn fft_fr(&self, data: &[Counting<TFr>], inverse: bool) -> Result<Vec<Counting<TFr>>, String> {
+        bump(|counts| counts.fft += 1);
+        self.0.fft_fr(unwrap_slice(data), inverse).map(wrap_vec)
+    }
+}
+
+impl<TFr: Fr, TFFTSettings: DAS<TFr>> DAS<Counting<TFr>> for Counting<TFFTSettings> {
+    fn das_fft_extension(&self, evens: &[Counting<TFr>]) -> Result<Vec<Counting<TFr>>, String> {
+        bump(|counts| counts.fft += 1);
+        self.0.das_fft_extension(unwrap_slice(evens)).map(wrap_vec)
+    }
+}
+
+impl<TFr: Fr, TPoly: Poly<TFr>> Poly<Counting<TFr>> for Counting<TPoly> {
+    fn new(size: usize) -> Self {
+        Self(TPoly::new(size))
+    }
+
+    fn from_coeffs(coeffs: &[Counting<TFr>]) -> Self {
+        Self(TPoly::from_coeffs(unwrap_slice(coeffs)))
+    }
+
+    fn get_coeff_at(&self, i: usize) -> Counting<TFr> {
+        Counting(self.0.get_coeff_at(i))
+    }
+
+    fn set_coeff_at(&mut self, i: usize, x: &Counting<TFr>) {
+        self.0.set_coeff_at(i, &x.0)
+    }
+
+    fn get_coeffs(&self) -> &[Counting<TFr>] {
+        wrap_slice(self.0.get_coeffs())
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn eval(&self, x: &Counting<TFr>) -> Counting<TFr> {
+        Counting(self.0.eval(&x.0))
+    }
+
+    fn scale(&mut self) {
+        self.0.scale()
+    }
+
+    fn unscale(&mut self) {
+        self.0.unscale()
+    }
+
+    fn inverse(&mut self, new_len: usize) -> Result<Self, String> {
+        self.0.inverse(new_len).map(Self)
+    }
+
+    fn div(&mut self, x: &Self) -> Result<Self, String> {
+        self.0.div(&x.0).map(Self)
+    }
+
+    fn long_div(&mut self, x: &Self) -> Result<Self, String> {
+        self.0.long_div(&x.0).map(Self)
+    }
+
+    fn fast_div(&mut self, x: &Self) -> Result<Self, String> {
+        self.0.fast_div(&x.0).map(Self)
+    }
+
+    fn mul_direct(&mut self, x: &Self, len: usize) -> Result<Self, String> {
+        self.0.mul_direct(&x.0, len).map(Self)
+    }
+
+    fn mul(&mut self, x: &Self, len: usize) -> Result<Self, String> {
+        self.0.mul(&x.0, len).map(Self)
+    }
+}
+
+impl<
+        TFr: Fr,
+        TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+        TG2: G2,
+        TFFTSettings: FFTSettings<TFr>,
+        TPoly: Poly<TFr>,
+        TG1Fp: G1Fp,
+        TG1Affine: G1Affine<TG1, TG1Fp>,
+        TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    >
+    KZGSettings<
+        Counting<TFr>,
+        Counting<TG1>,
+        TG2,
+        Counting<TFFTSettings>,
+        Counting<TPoly>,
+        TG1Fp,
+        Counting<TG1Affine>,
+    > for Counting<TKZGSettings>
+{
+    fn new(
+        secret_g1: &[Counting<TG1>],
+        secret_g2: &[TG2],
+        length: usize,
+        fs: &Counting<TFFTSettings>,
+    ) -> Result<Self, String> {
+        TKZGSettings::new(unwrap_slice(secret_g1), secret_g2, length, &fs.0).map(Self)
+    }
+
+    fn with_shared_points(base: &Self, with_precomputation: bool) -> Result<Self, String> {
+        TKZGSettings::with_shared_points(&base.0, with_precomputation).map(Self)
+    }
+
+    fn commit_to_poly(&self, p: &Counting<TPoly>) -> Result<Counting<TG1>, String> {
+        self.0.commit_to_poly(&p.0).map(Counting)
+    }
+
+    fn compute_proof_single(
+        &self,
+        p: &Counting<TPoly>,
+        x: &Counting<TFr>,
+    ) -> Result<Counting<TG1>, String> {
+        self.0.compute_proof_single(&p.0, &x.0).map(Counting)
+    }
+
+    fn check_proof_single(
+        &self,
+        com: &Counting<TG1>,
+        proof: &Counting<TG1>,
+        x: &Counting<TFr>,
+        value: &Counting<TFr>,
+    ) -> Result<bool, String> {
+        self.0.check_proof_single(&com.0, &proof.0, &x.0, &value.0)
+    }
+
+    fn compute_proof_multi(
+        &self,
+        p: &Counting<TPoly>,
+        x: &Counting<TFr>,
+        n: usize,
+    ) -> Result<Counting<TG1>, String> {
+        self.0.compute_proof_multi(&p.0, &x.0, n).map(Counting)
+    }
+
+    fn check_proof_multi(
+        &self,
+        com: &Counting<TG1>,
+        proof: &Counting<TG1>,
+        x: &Counting<TFr>,
+        values: &[Counting<TFr>],
+        n: usize,
+    ) -> Result<bool, String> {
+        self.0
+            .check_proof_multi(&com.0, &proof.0, &x.0, unwrap_slice(values), n)
+    }
+
+    fn get_expanded_roots_of_unity_at(&self, i: usize) -> Counting<TFr> {
+        Counting(self.0.get_expanded_roots_of_unity_at(i))
+    }
+
+    fn get_roots_of_unity_at(&self, i: usize) -> Counting<TFr> {
+        Counting(self.0.get_roots_of_unity_at(i))
+    }
+
+    fn get_fft_settings(&self) -> &Counting<TFFTSettings> {
+        wrap_ref(self.0.get_fft_settings())
+    }
+
+    fn get_g1_secret(&self) -> &[Counting<TG1>] {
+        wrap_slice(self.0.get_g1_secret())
+    }
+
+    fn get_g2_secret(&self) -> &[TG2] {
+        self.0.get_g2_secret()
+    }
+
+    fn get_g2_generator(&self) -> TG2 {
+        self.0.get_g2_generator()
+    }
+
+    // The backend's table is built for its own types, see `g1_lincomb` above
+    fn get_precomputation(
+        &self,
+    ) -> Option<&PrecomputationTable<Counting<TFr>, Counting<TG1>, TG1Fp, Counting<TG1Affine>>>
+    {
+        None
+    }
+
+    fn get_field_elements_per_blob(&self) -> usize {
+        self.0.get_field_elements_per_blob()
+    }
+
+    fn get_field_elements_per_ext_blob(&self) -> usize {
+        self.0.get_field_elements_per_ext_blob()
+    }
+
+    fn get_scale(&self) -> usize {
+        self.0.get_scale()
+    }
+}
@@ -0,0 +1,116 @@
+use std::boxed::Box;
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::string::String;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+use std::vec::Vec;
+
+use crate::{Fr, DAS};
+
+/// Runs blocking jobs away from the async executor.
+pub trait BlockingPool: Send + Sync {
+    fn spawn(&self, job: Box<dyn FnOnce() + Send>);
+}
+
+/// Starts a new OS thread for every job.
+pub struct ThreadPerJob;
+
+impl BlockingPool for ThreadPerJob {
+    fn spawn(&self, job: Box<dyn FnOnce() + Send>) {
+        thread::spawn(job);
+    }
+}
+
+/// Runs jobs on the global rayon thread pool.
+#[cfg(feature = "parallel")]
+pub struct RayonPool;
+
+#[cfg(feature = "parallel")]
+impl BlockingPool for RayonPool {
+    fn spawn(&self, job: Box<dyn FnOnce() + Send>) {
+        rayon::spawn(job);
+    }
+}
+
+/// Runs jobs on the blocking thread pool of the current tokio runtime, through
+/// `tokio::task::spawn_blocking`. Spawning outside of a runtime panics.
+#[cfg(feature = "tokio")]
+pub struct TokioPool;
+
+#[cfg(feature = "tokio")]
+impl BlockingPool for TokioPool {
+    fn spawn(&self, job: Box<dyn FnOnce() + Send>) {
+        tokio::task::spawn_blocking(job);
+    }
+}
+
+struct Shared<T> {
+    result: Option<thread::Result<T>>,
+    waker: Option<Waker>,
+}
+
+/// Resolves to the result of a job handed to a [`BlockingPool`]. A panic in the job is resumed
+/// when the future is polled.
+pub struct BlockingTask<T> {
+    shared: Arc<Mutex<Shared<T>>>,
+}
+
+impl<T: Send + 'static> BlockingTask<T> {
+    pub fn spawn(pool: &dyn BlockingPool, f: impl FnOnce() -> T + Send + 'static) -> Self {
+        let shared = Arc::new(Mutex::new(Shared {
+            result: None,
+            waker: None,
+        }));
+
+        let job_shared = shared.clone();
+        pool.spawn(Box::new(move || {
+            let result = panic::catch_unwind(AssertUnwindSafe(f));
+            let waker = {
+                let mut shared = job_shared.lock().unwrap();
+                shared.result = Some(result);
+                shared.waker.take()
+            };
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        }));
+
+        Self { shared }
+    }
+}
+
+impl<T> Future for BlockingTask<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let mut shared = self.shared.lock().unwrap();
+        match shared.result.take() {
+            Some(Ok(result)) => Poll::Ready(result),
+            Some(Err(payload)) => {
+                drop(shared);
+                panic::resume_unwind(payload)
+            }
+            None => {
+                shared.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Asynchronous counterpart of [`DAS`], implemented for every `DAS` type that can be shared
+/// across threads. The futures work with any executor.
+pub trait AsyncDAS<Coeff: Fr + 'static>: DAS<Coeff> + Send + Sync + Sized + 'static {
+    fn das_fft_extension_async(
+        self: Arc<Self>,
+        evens: Vec<Coeff>,
+        pool: &dyn BlockingPool,
+    ) -> BlockingTask<Result<Vec<Coeff>, String>> {
+        BlockingTask::spawn(pool, move || self.das_fft_extension(&evens))
+    }
+}
+
+impl<Coeff: Fr + 'static, T: DAS<Coeff> + Send + Sync + 'static> AsyncDAS<Coeff> for T {}
@@ -0,0 +1,134 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::digest::{DefaultDigest, Digest};
+
+pub const NUMBER_OF_COLUMNS: usize = 128;
+pub const NUMBER_OF_CUSTODY_GROUPS: usize = 128;
+pub const CUSTODY_REQUIREMENT: usize = 4;
+pub const SAMPLES_PER_SLOT: usize = 8;
+
+const COLUMNS_PER_GROUP: usize = NUMBER_OF_COLUMNS / NUMBER_OF_CUSTODY_GROUPS;
+
+fn hash_to_u64(data: &[u8]) -> u64 {
+    let hash = DefaultDigest::digest(data);
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&hash[..8]);
+    u64::from_le_bytes(bytes)
+}
+
+/// Custody groups of a node, sorted, as in the Fulu das-core spec. `node_id` is the 32 byte
+/// discv5 node id, read as a big-endian integer.
+pub fn get_custody_groups(
+    node_id: &[u8; 32],
+    custody_group_count: usize,
+) -> Result<Vec<u64>, String> {
+    if custody_group_count > NUMBER_OF_CUSTODY_GROUPS {
+        return Err(format!(
+            "Custody group count {} exceeds the number of custody groups {}",
+            custody_group_count, NUMBER_OF_CUSTODY_GROUPS
+        ));
+    }
+
+    if custody_group_count == NUMBER_OF_CUSTODY_GROUPS {
+        return Ok((0..NUMBER_OF_CUSTODY_GROUPS as u64).collect());
+    }
+
+    // The spec hashes the node id as a little-endian uint256 and increments it, wrapping at the
+    // maximum, until enough distinct groups are found
+    let mut current_id = *node_id;
+    current_id.reverse();
+
+    let mut groups = Vec::with_capacity(custody_group_count);
+    while groups.len() < custody_group_count {
+        let group = hash_to_u64(&current_id) % NUMBER_OF_CUSTODY_GROUPS as u64;
+        if !groups.contains(&group) {
+            groups.push(group);
+        }
+
+        for byte in current_id.iter_mut() {
+            let (next, overflow) = byte.overflowing_add(1);
+            *byte = next;
+            if !overflow {
+                break;
+            }
+        }
+    }
+
+    groups.sort_unstable();
+    Ok(groups)
+}
+
+pub fn compute_columns_for_custody_group(custody_group: u64) -> Result<Vec<u64>, String> {
+    if custody_group >= NUMBER_OF_CUSTODY_GROUPS as u64 {
+        return Err(format!(
+            "Custody group {} is out of range, there are {} groups",
+            custody_group, NUMBER_OF_CUSTODY_GROUPS
+        ));
+    }
+
+    Ok((0..COLUMNS_PER_GROUP as u64)
+        .map(|i| NUMBER_OF_CUSTODY_GROUPS as u64 * i + custody_group)
+        .collect())
+}
+
+/// Columns a node has to custody, sorted.
+pub fn get_custody_columns(
+    node_id: &[u8; 32],
+    custody_group_count: usize,
+) -> Result<Vec<u64>, String> {
+    let groups = get_custody_groups(node_id, custody_group_count)?;
+    let columns = columns_for_custody_groups(&groups)?;
+    Ok(columns.into_iter().map(|column| column as u64).collect())
+}
+
+/// Columns of all `groups`, sorted and without duplicates. They are usable directly as cell
+/// indices within an extended blob.
+pub fn columns_for_custody_groups(groups: &[u64]) -> Result<Vec<usize>, String> {
+    let mut columns = Vec::with_capacity(groups.len() * COLUMNS_PER_GROUP);
+    for &group in groups {
+        columns.extend(
+            compute_columns_for_custody_group(group)?
+                .into_iter()
+                .map(|column| column as usize),
+        );
+    }
+
+    columns.sort_unstable();
+    columns.dedup();
+    Ok(columns)
+}
+
+/// Distinct columns to sample in `slot`, sorted. `seed` should be local randomness that peers
+/// cannot predict, the same seed and slot always give the same columns.
+pub fn get_sample_columns(
+    seed: &[u8; 32],
+    slot: u64,
+    sample_count: usize,
+) -> Result<Vec<u64>, String> {
+    if sample_count > NUMBER_OF_COLUMNS {
+        return Err(format!(
+            "Sample count {} exceeds the number of columns {}",
+            sample_count, NUMBER_OF_COLUMNS
+        ));
+    }
+
+    let mut input = [0u8; 48];
+    input[..32].copy_from_slice(seed);
+    input[32..40].copy_from_slice(&slot.to_le_bytes());
+
+    let mut columns = Vec::with_capacity(sample_count);
+    let mut counter = 0u64;
+    while columns.len() < sample_count {
+        input[40..].copy_from_slice(&counter.to_le_bytes());
+        let column = hash_to_u64(&input) % NUMBER_OF_COLUMNS as u64;
+        if !columns.contains(&column) {
+            columns.push(column);
+        }
+        counter += 1;
+    }
+
+    columns.sort_unstable();
+    Ok(columns)
+}
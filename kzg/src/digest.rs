@@ -0,0 +1,64 @@
+use sha2::Digest as _;
+
+pub const DIGEST_BYTES: usize = 32;
+
+/// SHA-256 provider. Every implementation must produce identical output.
+pub trait Digest: Default {
+    fn update(&mut self, data: &[u8]);
+
+    fn finalize(self) -> [u8; DIGEST_BYTES];
+
+    fn chain_update(mut self, data: &[u8]) -> Self {
+        self.update(data);
+        self
+    }
+
+    fn digest(data: &[u8]) -> [u8; DIGEST_BYTES] {
+        Self::default().chain_update(data).finalize()
+    }
+}
+
+#[derive(Default, Clone)]
+pub struct Sha2Digest(sha2::Sha256);
+
+impl Digest for Sha2Digest {
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    fn finalize(self) -> [u8; DIGEST_BYTES] {
+        self.0.finalize().into()
+    }
+}
+
+#[cfg(feature = "ring")]
+#[derive(Clone)]
+pub struct RingDigest(ring::digest::Context);
+
+#[cfg(feature = "ring")]
+impl Default for RingDigest {
+    fn default() -> Self {
+        Self(ring::digest::Context::new(&ring::digest::SHA256))
+    }
+}
+
+#[cfg(feature = "ring")]
+impl Digest for RingDigest {
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    fn finalize(self) -> [u8; DIGEST_BYTES] {
+        let mut out = [0u8; DIGEST_BYTES];
+        out.copy_from_slice(self.0.finish().as_ref());
+        out
+    }
+}
+
+/// Provider used by [`crate::eip_4844::hash`] and [`crate::common_utils::expand_message_xmd`].
+#[cfg(not(feature = "ring"))]
+pub type DefaultDigest = Sha2Digest;
+
+/// Provider used by [`crate::eip_4844::hash`] and [`crate::common_utils::expand_message_xmd`].
+#[cfg(feature = "ring")]
+pub type DefaultDigest = RingDigest;
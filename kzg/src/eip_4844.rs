@@ -13,11 +13,13 @@ pub use blst::{blst_fr, blst_p1, blst_p2};
 use core::ffi::c_uint;
 use core::hash::Hash;
 use core::hash::Hasher;
-use sha2::{Digest, Sha256};
+use core::sync::atomic::{AtomicBool, Ordering};
 use siphasher::sip::SipHasher;
 
 use crate::common_utils::reverse_bit_order;
+use crate::digest::{DefaultDigest, Digest};
 use crate::msm::precompute::PrecomputationTable;
+use crate::transcript::Transcript;
 use crate::G1Affine;
 use crate::G1Fp;
 use crate::G1GetFp;
@@ -37,6 +39,20 @@ pub const BYTES_PER_BLOB: usize = BYTES_PER_FIELD_ELEMENT * FIELD_ELEMENTS_PER_B
 pub const BYTES_PER_FIELD_ELEMENT: usize = 32;
 pub const BYTES_PER_PROOF: usize = 48;
 pub const BYTES_PER_COMMITMENT: usize = 48;
+pub const VERSIONED_HASH_VERSION_KZG: u8 = 0x01;
+
+// Input of the point evaluation precompile: versioned hash, z, y, commitment and proof
+pub const POINT_EVALUATION_INPUT_LENGTH: usize =
+    32 + 2 * BYTES_PER_FIELD_ELEMENT + BYTES_PER_COMMITMENT + BYTES_PER_PROOF;
+
+/// Output of the point evaluation precompile on success: `FIELD_ELEMENTS_PER_BLOB` and the BLS
+/// modulus, each as a 32 byte big-endian integer.
+pub const POINT_EVALUATION_OUTPUT: [u8; 64] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x10,
+    0x00, 0x73, 0xed, 0xa7, 0x53, 0x29, 0x9d, 0x7d, 0x48, 0x33, 0x39, 0xd8, 0x08, 0x09, 0xa1, 0xd8,
+    0x05, 0x53, 0xbd, 0xa4, 0x02, 0xff, 0xfe, 0x5b, 0xfe, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00,
+    0x01,
+];
 
 pub const TRUSTED_SETUP_PATH: &str = "src/trusted_setup.txt";
 
@@ -46,6 +62,9 @@ pub static mut TRUSTED_SETUP_NUM_G1_POINTS: usize = 0;
 
 pub const TRUSTED_SETUP_NUM_G2_POINTS: usize = 65;
 
+// Number of field elements buffered at a time by the streaming functions
+pub const STREAM_CHUNK_ELEMENTS: usize = 256;
+
 pub const CHALLENGE_INPUT_SIZE: usize =
     FIAT_SHAMIR_PROTOCOL_DOMAIN.len() + 16 + BYTES_PER_BLOB + BYTES_PER_COMMITMENT;
 
@@ -57,6 +76,61 @@ pub const RANDOM_CHALLENGE_KZG_BATCH_DOMAIN: [u8; 16] = [
     82, 67, 75, 90, 71, 66, 65, 84, 67, 72, 95, 95, 95, 86, 49, 95,
 ]; // "RCKZGBATCH___V1_"
 
+pub const AGGREGATE_PROOF_DOMAIN: [u8; 16] = [
+    70, 83, 66, 76, 79, 66, 65, 71, 71, 82, 69, 71, 95, 86, 49, 95,
+]; // "FSBLOBAGGREG_V1_"
+
+/// Domain separation tags of the Fiat-Shamir challenges. Functions without a `domains` argument
+/// use [`DomainConfig::SPEC`], the EIP-4844 tags. Chains that reuse this library with their own
+/// tags can pass them to the `_with_domains` functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DomainConfig {
+    /// Prefix of the per-blob evaluation challenge
+    pub fiat_shamir_protocol: [u8; 16],
+    /// Prefix of the batch verification challenge
+    pub random_challenge_kzg_batch: [u8; 16],
+    /// Prefix of the challenge that combines the blobs of an aggregate proof
+    pub aggregate_proof: [u8; 16],
+}
+
+impl DomainConfig {
+    pub const SPEC: Self = Self {
+        fiat_shamir_protocol: FIAT_SHAMIR_PROTOCOL_DOMAIN,
+        random_challenge_kzg_batch: RANDOM_CHALLENGE_KZG_BATCH_DOMAIN,
+        aggregate_proof: AGGREGATE_PROOF_DOMAIN,
+    };
+}
+
+impl Default for DomainConfig {
+    fn default() -> Self {
+        Self::SPEC
+    }
+}
+
+/// Error of the `_cancellable` functions, which tells a cancellation apart from rejected inputs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CancellableError {
+    /// The flag was set before the work finished
+    Cancelled,
+    /// The inputs were rejected, with the message the non-cancellable function returns
+    Failed(String),
+}
+
+impl From<String> for CancellableError {
+    fn from(err: String) -> Self {
+        Self::Failed(err)
+    }
+}
+
+impl core::fmt::Display for CancellableError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Cancelled => f.write_str("Cancelled"),
+            Self::Failed(err) => f.write_str(err),
+        }
+    }
+}
+
 ////////////////////////////// C API for EIP-4844 //////////////////////////////
 
 pub type C_KZG_RET = c_uint;
@@ -253,7 +327,36 @@ pub fn bytes_of_uint64(out: &mut [u8], mut n: u64) {
 }
 
 pub fn hash(x: &[u8]) -> [u8; 32] {
-    Sha256::digest(x).into()
+    DefaultDigest::digest(x)
+}
+
+/// Versioned hash referencing a blob commitment from a transaction: the SHA-256 of the
+/// commitment with its first byte replaced by [`VERSIONED_HASH_VERSION_KZG`].
+pub fn kzg_to_versioned_hash<TG1: G1>(commitment: &TG1) -> [u8; 32] {
+    let mut versioned_hash = hash(&commitment.to_bytes());
+    versioned_hash[0] = VERSIONED_HASH_VERSION_KZG;
+    versioned_hash
+}
+
+pub fn kzg_to_versioned_hashes<TG1: G1>(commitments: &[TG1]) -> Vec<[u8; 32]> {
+    commitments.iter().map(kzg_to_versioned_hash).collect()
+}
+
+/// Serializes `proofs` back to back into `out`, which must be exactly
+/// `proofs.len() * BYTES_PER_PROOF` bytes long.
+pub fn serialize_proofs_into<TG1: G1>(proofs: &[TG1], out: &mut [u8]) -> Result<(), String> {
+    if out.len() != proofs.len() * BYTES_PER_PROOF {
+        return Err(format!(
+            "Invalid output length. Expected {}, got {}",
+            proofs.len() * BYTES_PER_PROOF,
+            out.len()
+        ));
+    }
+
+    for (proof, chunk) in proofs.iter().zip(out.chunks_exact_mut(BYTES_PER_PROOF)) {
+        proof.write_bytes(chunk)?;
+    }
+    Ok(())
 }
 
 #[macro_export]
@@ -281,12 +384,12 @@ fn poly_to_kzg_commitment<
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
 >(
-    p: &TPoly,
+    coeffs: &[TFr],
     s: &TKZGSettings,
 ) -> TG1 {
     TG1::g1_lincomb(
         s.get_g1_secret(),
-        p.get_coeffs(),
+        coeffs,
         FIELD_ELEMENTS_PER_BLOB,
         s.get_precomputation(),
     )
@@ -305,9 +408,111 @@ pub fn blob_to_kzg_commitment_rust<
     blob: &[TFr],
     settings: &TKZGSettings,
 ) -> Result<TG1, String> {
-    let polynomial = blob_to_polynomial(blob)?;
+    check_blob_length(blob)?;
+
+    Ok(poly_to_kzg_commitment(blob, settings))
+}
+
+/// Same as [`blob_to_kzg_commitment_rust`], but takes the blob as a stream of serialized field
+/// elements and only keeps [`STREAM_CHUNK_ELEMENTS`] of them in memory at a time. The stream
+/// must yield exactly [`FIELD_ELEMENTS_PER_BLOB`] canonical elements.
+pub fn blob_to_kzg_commitment_streaming_rust<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1LinComb<TFr, TG1Fp, TG1Affine> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    elements: impl IntoIterator<Item = [u8; BYTES_PER_FIELD_ELEMENT]>,
+    settings: &TKZGSettings,
+) -> Result<TG1, String> {
+    let g1_secret = settings.get_g1_secret();
+    check_g1_secret_len(g1_secret)?;
+
+    let mut commitment = TG1::identity();
+    for_each_stream_chunk(elements, |start, chunk: &[TFr]| {
+        let points = &g1_secret[start..start + chunk.len()];
+        commitment = commitment.add_or_dbl(&TG1::g1_lincomb(points, chunk, chunk.len(), None));
+        Ok(())
+    })?;
+
+    Ok(commitment)
+}
+
+fn check_g1_secret_len<TG1>(g1_secret: &[TG1]) -> Result<(), String> {
+    if g1_secret.len() < FIELD_ELEMENTS_PER_BLOB {
+        return Err(format!(
+            "Trusted setup has {} G1 points, need at least FIELD_ELEMENTS_PER_BLOB ({})",
+            g1_secret.len(),
+            FIELD_ELEMENTS_PER_BLOB
+        ));
+    }
+    Ok(())
+}
+
+// Parses a stream of exactly FIELD_ELEMENTS_PER_BLOB canonical field elements and hands them to
+// `f` in order, in chunks of at most STREAM_CHUNK_ELEMENTS along with the index of their first
+// element
+fn for_each_stream_chunk<TFr: Fr>(
+    elements: impl IntoIterator<Item = [u8; BYTES_PER_FIELD_ELEMENT]>,
+    mut f: impl FnMut(usize, &[TFr]) -> Result<(), String>,
+) -> Result<(), String> {
+    let mut chunk = Vec::with_capacity(STREAM_CHUNK_ELEMENTS);
+    let mut count = 0;
+
+    for bytes in elements {
+        if count == FIELD_ELEMENTS_PER_BLOB {
+            return Err(format!(
+                "Blob length must be FIELD_ELEMENTS_PER_BLOB ({}), got more",
+                FIELD_ELEMENTS_PER_BLOB
+            ));
+        }
+        chunk.push(TFr::from_bytes(&bytes)?);
+        count += 1;
+
+        if chunk.len() == STREAM_CHUNK_ELEMENTS || count == FIELD_ELEMENTS_PER_BLOB {
+            f(count - chunk.len(), &chunk)?;
+            chunk.clear();
+        }
+    }
+
+    if count != FIELD_ELEMENTS_PER_BLOB {
+        return Err(format!(
+            "Blob length must be FIELD_ELEMENTS_PER_BLOB ({}), got {}",
+            FIELD_ELEMENTS_PER_BLOB, count
+        ));
+    }
+
+    Ok(())
+}
 
-    Ok(poly_to_kzg_commitment(&polynomial, settings))
+/// Commits to every blob in `blobs`, in order. With the `parallel` feature the blobs are
+/// committed to concurrently.
+///
+/// There is no separate setup step: the fixed-base MSM table is the one `settings` built when it
+/// was loaded ([`KZGSettings::get_precomputation`]), and every blob's `g1_lincomb` reads it from
+/// there, so it is shared across the batch and never rebuilt per blob. Settings loaded without a
+/// table fall back to the plain MSM for each blob.
+pub fn blob_to_kzg_commitment_batch_rust<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1LinComb<TFr, TG1Fp, TG1Affine> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine> + Sync,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    B: AsRef<[TFr]> + Sync,
+>(
+    blobs: &[B],
+    settings: &TKZGSettings,
+) -> Result<Vec<TG1>, String> {
+    cfg_into_iter!(blobs)
+        .map(|blob| blob_to_kzg_commitment_rust(blob.as_ref(), settings))
+        .collect()
 }
 
 pub fn compute_powers<TFr: Fr>(base: &TFr, num_powers: usize) -> Vec<TFr> {
@@ -327,49 +532,102 @@ fn compute_r_powers<TG1: G1, TFr: Fr>(
     zs_fr: &[TFr],
     ys_fr: &[TFr],
     proofs_g1: &[TG1],
+    domains: &DomainConfig,
 ) -> Result<Vec<TFr>, String> {
-    let n = commitments_g1.len();
-    let input_size =
-        32 + n * (BYTES_PER_COMMITMENT + 2 * BYTES_PER_FIELD_ELEMENT + BYTES_PER_PROOF);
+    compute_r_powers_with_domain::<TG1, TFr, DefaultDigest>(
+        &domains.random_challenge_kzg_batch,
+        commitments_g1,
+        zs_fr,
+        ys_fr,
+        proofs_g1,
+        cfg!(feature = "strict-challenges"),
+    )
+}
 
-    let mut bytes: Vec<u8> = vec![0; input_size];
+/// Computes the powers of the batch verification challenge r, hashing the transcript with `D`.
+///
+/// The spec uses r as derived, even if it is zero or one, where the batch check degenerates to
+/// the first item alone or to an unweighted sum. With `strict`, such an r is re-derived from
+/// the transcript followed by a one-byte counter, and an error is returned if every counter
+/// value still gives a degenerate r. This deviates from the spec, so it is off by default
+/// (see the `strict-challenges` feature).
+pub fn compute_r_powers_with_digest<TG1: G1, TFr: Fr, D: Digest>(
+    commitments_g1: &[TG1],
+    zs_fr: &[TFr],
+    ys_fr: &[TFr],
+    proofs_g1: &[TG1],
+    strict: bool,
+) -> Result<Vec<TFr>, String> {
+    compute_r_powers_with_domain::<TG1, TFr, D>(
+        &RANDOM_CHALLENGE_KZG_BATCH_DOMAIN,
+        commitments_g1,
+        zs_fr,
+        ys_fr,
+        proofs_g1,
+        strict,
+    )
+}
 
-    // Copy domain separator
-    bytes[..16].copy_from_slice(&RANDOM_CHALLENGE_KZG_BATCH_DOMAIN);
-    bytes_of_uint64(&mut bytes[16..24], FIELD_ELEMENTS_PER_BLOB as u64);
-    bytes_of_uint64(&mut bytes[24..32], n as u64);
-    let mut offset = 32;
+fn compute_r_powers_with_domain<TG1: G1, TFr: Fr, D: Digest>(
+    domain: &[u8; 16],
+    commitments_g1: &[TG1],
+    zs_fr: &[TFr],
+    ys_fr: &[TFr],
+    proofs_g1: &[TG1],
+    strict: bool,
+) -> Result<Vec<TFr>, String> {
+    let n = commitments_g1.len();
+    if zs_fr.len() != n || ys_fr.len() != n || proofs_g1.len() != n {
+        return Err("Invalid amount of arguments".to_string());
+    }
 
-    for i in 0..n {
-        // Copy commitment
-        let v = commitments_g1[i].to_bytes();
-        bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
-        offset += BYTES_PER_COMMITMENT;
+    compute_r_powers_from_transcript::<TG1, TFr, D>(
+        domain,
+        |i| &commitments_g1[i],
+        zs_fr,
+        ys_fr,
+        proofs_g1,
+        strict,
+    )
+}
 
-        // Copy evaluation challenge
-        let v = zs_fr[i].to_bytes();
-        bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
-        offset += BYTES_PER_FIELD_ELEMENT;
+// `commitment_at(i)` is the commitment opened by the i-th proof, so a commitment shared by every
+// opening is still bound to each of them without being copied
+fn compute_r_powers_from_transcript<'a, TG1: G1 + 'a, TFr: Fr, D: Digest>(
+    domain: &[u8; 16],
+    commitment_at: impl Fn(usize) -> &'a TG1,
+    zs_fr: &[TFr],
+    ys_fr: &[TFr],
+    proofs_g1: &[TG1],
+    strict: bool,
+) -> Result<Vec<TFr>, String> {
+    let n = zs_fr.len();
 
-        // Copy polynomial's evaluation value
-        let v = ys_fr[i].to_bytes();
-        bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
-        offset += BYTES_PER_FIELD_ELEMENT;
+    let mut transcript = Transcript::<D>::with_capacity(
+        domain,
+        16 + n * (BYTES_PER_COMMITMENT + 2 * BYTES_PER_FIELD_ELEMENT + BYTES_PER_PROOF),
+    );
+    transcript.append_u64(FIELD_ELEMENTS_PER_BLOB as u64);
+    transcript.append_u64(n as u64);
 
-        // Copy proof
-        let v = proofs_g1[i].to_bytes();
-        bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
-        offset += BYTES_PER_PROOF;
+    for i in 0..n {
+        transcript.append_g1(commitment_at(i));
+        transcript.append_fr(&zs_fr[i]);
+        transcript.append_fr(&ys_fr[i]);
+        transcript.append_g1(&proofs_g1[i]);
     }
 
-    // Make sure we wrote the entire buffer
-    if offset != input_size {
-        return Err(String::from("Error while copying commitments"));
-    }
+    let mut r: TFr = transcript.challenge_fr();
 
-    // Now let's create the challenge!
-    let eval_challenge = hash(&bytes);
-    let r = hash_to_bls_field(&eval_challenge);
+    if strict {
+        let mut counter: u8 = 0;
+        while r.is_zero() || r.is_one() {
+            counter = counter
+                .checked_add(1)
+                .ok_or_else(|| String::from("Could not derive a non-degenerate batch challenge"))?;
+            r = transcript.challenge_fr_with_counter(counter);
+        }
+    }
 
     Ok(compute_powers(&r, n))
 }
@@ -389,30 +647,66 @@ fn verify_kzg_proof_batch<
     ys_fr: &[TFr],
     proofs_g1: &[TG1],
     ts: &TKZGSettings,
-) -> Result<bool, String> {
+    domains: &DomainConfig,
+    cancel: &AtomicBool,
+) -> Result<bool, CancellableError> {
     let n = commitments_g1.len();
-    let mut c_minus_y: Vec<TG1> = Vec::with_capacity(n);
-    let mut r_times_z: Vec<TFr> = Vec::with_capacity(n);
 
     // Compute the random lincomb challenges
-    let r_powers = compute_r_powers(commitments_g1, zs_fr, ys_fr, proofs_g1)?;
+    let r_powers = compute_r_powers(commitments_g1, zs_fr, ys_fr, proofs_g1, domains)?;
+
+    verify_kzg_proof_batch_with_r_powers(
+        |r_powers| TG1::g1_lincomb(commitments_g1, r_powers, n, None),
+        zs_fr,
+        ys_fr,
+        proofs_g1,
+        &r_powers,
+        ts,
+        cancel,
+    )
+}
+
+// Finishes a batch check once the challenges are known. `commitment_lincomb` computes
+// \sum r^i C_i from the powers of r.
+fn verify_kzg_proof_batch_with_r_powers<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + PairingVerify<TG1, TG2> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    commitment_lincomb: impl FnOnce(&[TFr]) -> TG1,
+    zs_fr: &[TFr],
+    ys_fr: &[TFr],
+    proofs_g1: &[TG1],
+    r_powers: &[TFr],
+    ts: &TKZGSettings,
+    cancel: &AtomicBool,
+) -> Result<bool, CancellableError> {
+    let n = proofs_g1.len();
+    let mut r_times_z: Vec<TFr> = Vec::with_capacity(n);
 
     // Compute \sum r^i * Proof_i
-    let proof_lincomb = TG1::g1_lincomb(proofs_g1, &r_powers, n, None);
+    check_cancelled(cancel)?;
+    let proof_lincomb = TG1::g1_lincomb(proofs_g1, r_powers, n, None);
 
     for i in 0..n {
-        // Get [y_i]
-        let ys_encrypted = TG1::generator().mul(&ys_fr[i]);
-        // Get C_i - [y_i]
-        c_minus_y.push(commitments_g1[i].sub(&ys_encrypted));
         // Get r^i * z_i
         r_times_z.push(r_powers[i].mul(&zs_fr[i]));
     }
 
     // Get \sum r^i z_i Proof_i
+    check_cancelled(cancel)?;
     let proof_z_lincomb = TG1::g1_lincomb(proofs_g1, &r_times_z, n, None);
-    // Get \sum r^i (C_i - [y_i])
-    let c_minus_y_lincomb = TG1::g1_lincomb(&c_minus_y, &r_powers, n, None);
+    // Get \sum r^i (C_i - [y_i]) as \sum r^i C_i - [\sum r^i y_i], which needs a single
+    // multiplication of the generator instead of one per proof
+    check_cancelled(cancel)?;
+    let c_lincomb = commitment_lincomb(r_powers);
+    let r_times_y = TFr::dot_product(r_powers, ys_fr)?;
+    let c_minus_y_lincomb = c_lincomb.sub(&TG1::generator().mul(&r_times_y));
 
     // Get C_minus_y_lincomb + proof_z_lincomb
     let rhs_g1 = c_minus_y_lincomb.add_or_dbl(&proof_z_lincomb);
@@ -422,10 +716,158 @@ fn verify_kzg_proof_batch<
         &proof_lincomb,
         &ts.get_g2_secret()[1],
         &rhs_g1,
-        &TG2::generator(),
+        &ts.get_g2_generator(),
     ))
 }
 
+/// Verifies openings of many commitments, each at its own point, with a single pairing check.
+pub fn verify_kzg_proof_batch_rust<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + PairingVerify<TG1, TG2> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    commitments_g1: &[TG1],
+    zs_fr: &[TFr],
+    ys_fr: &[TFr],
+    proofs_g1: &[TG1],
+    ts: &TKZGSettings,
+) -> Result<bool, String> {
+    verify_kzg_proof_batch_with_domains_rust(
+        commitments_g1,
+        zs_fr,
+        ys_fr,
+        proofs_g1,
+        ts,
+        &DomainConfig::SPEC,
+    )
+}
+
+/// Same as [`verify_kzg_proof_batch_rust`], with the challenge prefixed by the batch tag in
+/// `domains`.
+pub fn verify_kzg_proof_batch_with_domains_rust<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + PairingVerify<TG1, TG2> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    commitments_g1: &[TG1],
+    zs_fr: &[TFr],
+    ys_fr: &[TFr],
+    proofs_g1: &[TG1],
+    ts: &TKZGSettings,
+    domains: &DomainConfig,
+) -> Result<bool, String> {
+    let n = commitments_g1.len();
+    if zs_fr.len() != n || ys_fr.len() != n || proofs_g1.len() != n {
+        return Err("Invalid amount of arguments".to_string());
+    }
+    if n == 0 {
+        return Ok(true);
+    }
+
+    validate_batched_input(commitments_g1, proofs_g1)?;
+
+    verify_kzg_proof_batch(
+        commitments_g1,
+        zs_fr,
+        ys_fr,
+        proofs_g1,
+        ts,
+        domains,
+        &AtomicBool::new(false),
+    )
+    .map_err(|err| err.to_string())
+}
+
+/// Verifies openings of one commitment at many points with a single pairing check, instead of
+/// one [`verify_kzg_proof_rust`] call per point. Accepts the same inputs as
+/// [`verify_kzg_proof_batch_rust`] given the commitment once per opening, with one MSM fewer.
+pub fn verify_kzg_proof_batch_same_commitment_rust<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + PairingVerify<TG1, TG2> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    commitment_g1: &TG1,
+    zs_fr: &[TFr],
+    ys_fr: &[TFr],
+    proofs_g1: &[TG1],
+    ts: &TKZGSettings,
+) -> Result<bool, String> {
+    verify_kzg_proof_batch_same_commitment_with_domains_rust(
+        commitment_g1,
+        zs_fr,
+        ys_fr,
+        proofs_g1,
+        ts,
+        &DomainConfig::SPEC,
+    )
+}
+
+/// Same as [`verify_kzg_proof_batch_same_commitment_rust`], with the challenge prefixed by the
+/// batch tag in `domains`.
+pub fn verify_kzg_proof_batch_same_commitment_with_domains_rust<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + PairingVerify<TG1, TG2> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    commitment_g1: &TG1,
+    zs_fr: &[TFr],
+    ys_fr: &[TFr],
+    proofs_g1: &[TG1],
+    ts: &TKZGSettings,
+    domains: &DomainConfig,
+) -> Result<bool, String> {
+    if zs_fr.len() != ys_fr.len() || zs_fr.len() != proofs_g1.len() {
+        return Err("Invalid amount of arguments".to_string());
+    }
+    if zs_fr.is_empty() {
+        return Ok(true);
+    }
+
+    validate_batched_input(core::slice::from_ref(commitment_g1), proofs_g1)?;
+
+    // The challenge has to bind the commitment to every opening, like in the general batch
+    let r_powers = compute_r_powers_from_transcript::<TG1, TFr, DefaultDigest>(
+        &domains.random_challenge_kzg_batch,
+        |_| commitment_g1,
+        zs_fr,
+        ys_fr,
+        proofs_g1,
+        cfg!(feature = "strict-challenges"),
+    )?;
+
+    // Every opening is of the same commitment, so \sum r^i C = (\sum r^i) C
+    verify_kzg_proof_batch_with_r_powers(
+        |r_powers| commitment_g1.mul(&TFr::sum_slice(r_powers)),
+        zs_fr,
+        ys_fr,
+        proofs_g1,
+        &r_powers,
+        ts,
+        &AtomicBool::new(false),
+    )
+    .map_err(|err| err.to_string())
+}
+
 pub fn compute_kzg_proof_rust<
     TFr: Fr + Copy,
     TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
@@ -440,8 +882,8 @@ pub fn compute_kzg_proof_rust<
     z: &TFr,
     s: &TKZGSettings,
 ) -> Result<(TG1, TFr), String> {
-    let polynomial = blob_to_polynomial(blob)?;
-    let y = evaluate_polynomial_in_evaluation_form(&polynomial, z, s)?;
+    check_blob_length(blob)?;
+    let y = evaluate_blob_in_evaluation_form(blob, z, s)?;
 
     let mut tmp: TFr;
 
@@ -452,7 +894,6 @@ pub fn compute_kzg_proof_rust<
     let mut inverses: Vec<TFr> = vec![TFr::default(); FIELD_ELEMENTS_PER_BLOB];
 
     let roots_of_unity = s.get_fft_settings().get_roots_of_unity();
-    let poly_coeffs = polynomial.get_coeffs();
 
     for i in 0..FIELD_ELEMENTS_PER_BLOB {
         if z.equals(&roots_of_unity[i]) {
@@ -462,7 +903,7 @@ pub fn compute_kzg_proof_rust<
             continue;
         }
         // (p_i - y) / (ω_i - z)
-        q.set_coeff_at(i, &poly_coeffs[i].sub(&y));
+        q.set_coeff_at(i, &blob[i].sub(&y));
         inverses_in[i] = roots_of_unity[i].sub(z);
     }
 
@@ -492,7 +933,7 @@ pub fn compute_kzg_proof_rust<
                 continue;
             }
             // Build numerator: ω_i * (p_i - y)
-            tmp = poly_coeffs[i].sub(&y);
+            tmp = blob[i].sub(&y);
             tmp = tmp.mul(&roots_of_unity[i]);
             // Do the division: (p_i - y) * ω_i / (z * (z - ω_i))
             tmp = tmp.mul(&inverses[i]);
@@ -523,18 +964,13 @@ pub fn compute_blob_kzg_proof_rust<
     commitment: &TG1,
     ts: &TKZGSettings,
 ) -> Result<TG1, String> {
-    if !commitment.is_inf() && !commitment.is_valid() {
-        return Err("Invalid commitment".to_string());
-    }
-
-    let evaluation_challenge_fr = compute_challenge(blob, commitment);
-    let (proof, _) = compute_kzg_proof_rust(blob, &evaluation_challenge_fr, ts)?;
-    Ok(proof)
+    compute_blob_kzg_proof_with_domains_rust(blob, commitment, ts, &DomainConfig::SPEC)
 }
 
-pub fn verify_kzg_proof_rust<
-    TFr: Fr,
-    TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
+/// Same as [`compute_blob_kzg_proof_rust`], with the challenge prefixed by the tag in `domains`.
+pub fn compute_blob_kzg_proof_with_domains_rust<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
     TG2: G2,
     TFFTSettings: FFTSettings<TFr>,
     TPoly: Poly<TFr>,
@@ -542,48 +978,387 @@ pub fn verify_kzg_proof_rust<
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
 >(
+    blob: &[TFr],
     commitment: &TG1,
-    z: &TFr,
-    y: &TFr,
-    proof: &TG1,
-    s: &TKZGSettings,
-) -> Result<bool, String> {
+    ts: &TKZGSettings,
+    domains: &DomainConfig,
+) -> Result<TG1, String> {
     if !commitment.is_inf() && !commitment.is_valid() {
         return Err("Invalid commitment".to_string());
     }
-    if !proof.is_inf() && !proof.is_valid() {
-        return Err("Invalid proof".to_string());
+    // The challenge is computed over the raw blob, so reject bad lengths before hashing it
+    if blob.len() != FIELD_ELEMENTS_PER_BLOB {
+        return Err(format!(
+            "Blob length must be FIELD_ELEMENTS_PER_BLOB ({}), got {}",
+            FIELD_ELEMENTS_PER_BLOB,
+            blob.len()
+        ));
     }
 
-    s.check_proof_single(commitment, proof, z, y)
+    let evaluation_challenge_fr = compute_challenge(blob, commitment, domains);
+    let (proof, _) = compute_kzg_proof_rust(blob, &evaluation_challenge_fr, ts)?;
+    Ok(proof)
 }
 
-pub fn verify_blob_kzg_proof_rust<
+/// Same as [`compute_kzg_proof_rust`], but reads the blob from `elements` like
+/// [`blob_to_kzg_commitment_streaming_rust`]. `y` depends on the whole blob and the quotient on
+/// `y`, so the blob is read twice: `elements` is called once per pass and must yield the same
+/// stream each time.
+pub fn compute_kzg_proof_streaming_rust<
     TFr: Fr + Copy,
-    TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
     TG2: G2,
     TFFTSettings: FFTSettings<TFr>,
     TPoly: Poly<TFr>,
     TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
+    I: IntoIterator<Item = [u8; BYTES_PER_FIELD_ELEMENT]>,
 >(
-    blob: &[TFr],
-    commitment_g1: &TG1,
-    proof_g1: &TG1,
-    ts: &TKZGSettings,
-) -> Result<bool, String> {
-    if !commitment_g1.is_inf() && !commitment_g1.is_valid() {
-        return Err("Invalid commitment".to_string());
-    }
-    if !proof_g1.is_inf() && !proof_g1.is_valid() {
-        return Err("Invalid proof".to_string());
-    }
-
-    let polynomial = blob_to_polynomial(blob)?;
-    let evaluation_challenge_fr = compute_challenge(blob, commitment_g1);
-    let y_fr = evaluate_polynomial_in_evaluation_form(&polynomial, &evaluation_challenge_fr, ts)?;
-    verify_kzg_proof_rust(commitment_g1, &evaluation_challenge_fr, &y_fr, proof_g1, ts)
+    mut elements: impl FnMut() -> I,
+    z: &TFr,
+    settings: &TKZGSettings,
+) -> Result<(TG1, TFr), String> {
+    let g1_secret = settings.get_g1_secret();
+    check_g1_secret_len(g1_secret)?;
+    let roots_of_unity =
+        &settings.get_fft_settings().get_roots_of_unity()[..FIELD_ELEMENTS_PER_BLOB];
+    // Index of z in the domain, where the quotient is built differently
+    let m = roots_of_unity.iter().position(|root| root.equals(z));
+
+    let mut inverses_in = Vec::with_capacity(STREAM_CHUNK_ELEMENTS);
+    let mut inverses = vec![TFr::default(); STREAM_CHUNK_ELEMENTS];
+
+    // First pass: y, as in `evaluate_blob_in_evaluation_form`
+    let mut y = TFr::zero();
+    for_each_stream_chunk(elements(), |start, chunk: &[TFr]| {
+        let roots = &roots_of_unity[start..start + chunk.len()];
+        match m {
+            Some(m) => {
+                if (start..start + chunk.len()).contains(&m) {
+                    y = chunk[m - start];
+                }
+            }
+            None => {
+                inverses_in.clear();
+                inverses_in.extend(roots.iter().map(|root| z.sub(root)));
+                fr_batch_inv(&mut inverses, &inverses_in, chunk.len())?;
+                for ((inverse, root), p) in inverses.iter().zip(roots).zip(chunk) {
+                    y = y.add(&inverse.mul(root).mul(p));
+                }
+            }
+        }
+        Ok(())
+    })?;
+    if m.is_none() {
+        y = y.div(&TFr::from_u64(FIELD_ELEMENTS_PER_BLOB as u64))?;
+        y = y.mul(&z.pow(FIELD_ELEMENTS_PER_BLOB).sub(&TFr::one()));
+    }
+
+    // Second pass: the quotient, committed to chunk by chunk, as in `compute_kzg_proof_rust`
+    let mut proof = TG1::identity();
+    let mut q = Vec::with_capacity(STREAM_CHUNK_ELEMENTS);
+    let mut q_m = TFr::zero();
+    for_each_stream_chunk(elements(), |start, chunk: &[TFr]| {
+        let roots = &roots_of_unity[start..start + chunk.len()];
+
+        // (p_i - y) / (ω_i - z), with ω_m == z left as zero until the whole blob is seen
+        inverses_in.clear();
+        inverses_in.extend(roots.iter().enumerate().map(|(i, root)| {
+            if m == Some(start + i) {
+                TFr::one()
+            } else {
+                root.sub(z)
+            }
+        }));
+        fr_batch_inv(&mut inverses, &inverses_in, chunk.len())?;
+        q.clear();
+        q.extend(
+            chunk
+                .iter()
+                .zip(&inverses)
+                .enumerate()
+                .map(|(i, (p, inverse))| {
+                    if m == Some(start + i) {
+                        TFr::zero()
+                    } else {
+                        p.sub(&y).mul(inverse)
+                    }
+                }),
+        );
+
+        if let Some(m) = m {
+            // ω_i * (p_i - y) / (z * (z - ω_i)), summed into the quotient at ω_m
+            inverses_in.clear();
+            inverses_in.extend(roots.iter().enumerate().map(|(i, root)| {
+                if m == start + i {
+                    TFr::one()
+                } else {
+                    z.sub(root).mul(z)
+                }
+            }));
+            fr_batch_inv(&mut inverses, &inverses_in, chunk.len())?;
+            for (i, ((p, root), inverse)) in chunk.iter().zip(roots).zip(&inverses).enumerate() {
+                if m != start + i {
+                    q_m = q_m.add(&p.sub(&y).mul(root).mul(inverse));
+                }
+            }
+        }
+
+        let points = &g1_secret[start..start + chunk.len()];
+        proof = proof.add_or_dbl(&TG1::g1_lincomb(points, &q, q.len(), None));
+        Ok(())
+    })?;
+    if let Some(m) = m {
+        proof = proof.add_or_dbl(&g1_secret[m].mul(&q_m));
+    }
+
+    Ok((proof, y))
+}
+
+/// Same as [`compute_blob_kzg_proof_rust`], but reads the blob from `elements` like
+/// [`blob_to_kzg_commitment_streaming_rust`]. The challenge is a hash of the whole blob, so it
+/// can't be derived in the same pass that uses it: the blob is read three times, see
+/// [`compute_kzg_proof_streaming_rust`].
+pub fn compute_blob_kzg_proof_streaming_rust<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    I: IntoIterator<Item = [u8; BYTES_PER_FIELD_ELEMENT]>,
+>(
+    elements: impl FnMut() -> I,
+    commitment: &TG1,
+    settings: &TKZGSettings,
+) -> Result<TG1, String> {
+    compute_blob_kzg_proof_streaming_with_domains_rust(
+        elements,
+        commitment,
+        settings,
+        &DomainConfig::SPEC,
+    )
+}
+
+/// Same as [`compute_blob_kzg_proof_streaming_rust`], with the challenge prefixed by the tag in
+/// `domains`.
+pub fn compute_blob_kzg_proof_streaming_with_domains_rust<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    I: IntoIterator<Item = [u8; BYTES_PER_FIELD_ELEMENT]>,
+>(
+    mut elements: impl FnMut() -> I,
+    commitment: &TG1,
+    settings: &TKZGSettings,
+    domains: &DomainConfig,
+) -> Result<TG1, String> {
+    if !commitment.is_inf() && !commitment.is_valid() {
+        return Err("Invalid commitment".to_string());
+    }
+
+    let mut transcript = challenge_transcript(domains);
+    for_each_stream_chunk(elements(), |_, chunk: &[TFr]| {
+        chunk.iter().for_each(|field| transcript.append_fr(field));
+        Ok(())
+    })?;
+    transcript.append_g1(commitment);
+    let evaluation_challenge_fr: TFr = transcript.challenge_fr();
+
+    let (proof, _) =
+        compute_kzg_proof_streaming_rust(elements, &evaluation_challenge_fr, settings)?;
+    Ok(proof)
+}
+
+/// Runs the EIP-4844 point evaluation precompile on its raw input. Returns an error if the input
+/// is malformed or its versioned hash does not belong to its commitment, the precompile fails in
+/// both cases. Otherwise returns whether the proof is valid.
+pub fn verify_point_evaluation_precompile_rust<
+    TFr: Fr,
+    TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    input: &[u8; POINT_EVALUATION_INPUT_LENGTH],
+    s: &TKZGSettings,
+) -> Result<bool, String> {
+    let (versioned_hash, rest) = input.split_at(32);
+    let (z, rest) = rest.split_at(BYTES_PER_FIELD_ELEMENT);
+    let (y, rest) = rest.split_at(BYTES_PER_FIELD_ELEMENT);
+    let (commitment, proof) = rest.split_at(BYTES_PER_COMMITMENT);
+
+    let commitment = TG1::from_bytes(commitment)?;
+    if kzg_to_versioned_hash(&commitment) != versioned_hash {
+        return Err("Versioned hash does not match the commitment".to_string());
+    }
+
+    verify_kzg_proof_rust(
+        &commitment,
+        &TFr::from_bytes(z)?,
+        &TFr::from_bytes(y)?,
+        &TG1::from_bytes(proof)?,
+        s,
+    )
+}
+
+pub fn verify_kzg_proof_rust<
+    TFr: Fr,
+    TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    commitment: &TG1,
+    z: &TFr,
+    y: &TFr,
+    proof: &TG1,
+    s: &TKZGSettings,
+) -> Result<bool, String> {
+    if !commitment.is_inf() && !commitment.is_valid() {
+        return Err("Invalid commitment".to_string());
+    }
+    if !proof.is_inf() && !proof.is_valid() {
+        return Err("Invalid proof".to_string());
+    }
+
+    s.check_proof_single(commitment, proof, z, y)
+}
+
+pub fn verify_blob_kzg_proof_rust<
+    TFr: Fr + Copy,
+    TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    blob: &[TFr],
+    commitment_g1: &TG1,
+    proof_g1: &TG1,
+    ts: &TKZGSettings,
+) -> Result<bool, String> {
+    verify_blob_kzg_proof_with_domains_rust(blob, commitment_g1, proof_g1, ts, &DomainConfig::SPEC)
+}
+
+/// Same as [`verify_blob_kzg_proof_rust`], with the challenge prefixed by the tag in `domains`.
+pub fn verify_blob_kzg_proof_with_domains_rust<
+    TFr: Fr + Copy,
+    TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    blob: &[TFr],
+    commitment_g1: &TG1,
+    proof_g1: &TG1,
+    ts: &TKZGSettings,
+    domains: &DomainConfig,
+) -> Result<bool, String> {
+    if !commitment_g1.is_inf() && !commitment_g1.is_valid() {
+        return Err("Invalid commitment".to_string());
+    }
+    if !proof_g1.is_inf() && !proof_g1.is_valid() {
+        return Err("Invalid proof".to_string());
+    }
+
+    verify_blob_kzg_proof_unchecked(blob, commitment_g1, proof_g1, ts, domains)
+}
+
+// Callers must have checked that both points are valid
+pub(crate) fn verify_blob_kzg_proof_unchecked<
+    TFr: Fr + Copy,
+    TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    blob: &[TFr],
+    commitment_g1: &TG1,
+    proof_g1: &TG1,
+    ts: &TKZGSettings,
+    domains: &DomainConfig,
+) -> Result<bool, String> {
+    check_blob_length(blob)?;
+    let evaluation_challenge_fr = compute_challenge(blob, commitment_g1, domains);
+    let y_fr = evaluate_blob_in_evaluation_form(blob, &evaluation_challenge_fr, ts)?;
+    ts.check_proof_single(commitment_g1, proof_g1, &evaluation_challenge_fr, &y_fr)
+}
+
+// The identity commits to the zero polynomial; the spec accepts it, strict callers treat it as a bug
+fn reject_identity<TG1: G1>(commitment: &TG1, proof: &TG1) -> Result<(), String> {
+    if commitment.is_inf() {
+        return Err("Identity commitment".to_string());
+    }
+    if proof.is_inf() {
+        return Err("Identity proof".to_string());
+    }
+
+    Ok(())
+}
+
+/// Same as [`verify_kzg_proof_rust`], but errors if the commitment or the proof is the identity.
+pub fn verify_kzg_proof_strict_rust<
+    TFr: Fr,
+    TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    commitment: &TG1,
+    z: &TFr,
+    y: &TFr,
+    proof: &TG1,
+    s: &TKZGSettings,
+) -> Result<bool, String> {
+    reject_identity(commitment, proof)?;
+    verify_kzg_proof_rust(commitment, z, y, proof, s)
+}
+
+/// Same as [`verify_blob_kzg_proof_rust`], but errors if the commitment or the proof is the
+/// identity.
+pub fn verify_blob_kzg_proof_strict_rust<
+    TFr: Fr + Copy,
+    TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    blob: &[TFr],
+    commitment_g1: &TG1,
+    proof_g1: &TG1,
+    ts: &TKZGSettings,
+) -> Result<bool, String> {
+    reject_identity(commitment_g1, proof_g1)?;
+    verify_blob_kzg_proof_rust(blob, commitment_g1, proof_g1, ts)
 }
 
 fn compute_challenges_and_evaluate_polynomial<
@@ -595,19 +1370,23 @@ fn compute_challenges_and_evaluate_polynomial<
     TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
+    B: AsRef<[TFr]>,
 >(
-    blobs: &[Vec<TFr>],
+    blobs: &[B],
     commitments_g1: &[TG1],
     ts: &TKZGSettings,
-) -> Result<(Vec<TFr>, Vec<TFr>), String> {
+    domains: &DomainConfig,
+    cancel: &AtomicBool,
+) -> Result<(Vec<TFr>, Vec<TFr>), CancellableError> {
     let mut evaluation_challenges_fr = Vec::with_capacity(blobs.len());
     let mut ys_fr = Vec::with_capacity(blobs.len());
 
     for i in 0..blobs.len() {
-        let polynomial = blob_to_polynomial(&blobs[i])?;
-        let evaluation_challenge_fr = compute_challenge(&blobs[i], &commitments_g1[i]);
-        let y_fr =
-            evaluate_polynomial_in_evaluation_form(&polynomial, &evaluation_challenge_fr, ts)?;
+        check_cancelled(cancel)?;
+        let blob = blobs[i].as_ref();
+        check_blob_length(blob)?;
+        let evaluation_challenge_fr = compute_challenge(blob, &commitments_g1[i], domains);
+        let y_fr = evaluate_blob_in_evaluation_form(blob, &evaluation_challenge_fr, ts)?;
 
         evaluation_challenges_fr.push(evaluation_challenge_fr);
         ys_fr.push(y_fr);
@@ -616,6 +1395,16 @@ fn compute_challenges_and_evaluate_polynomial<
     Ok((evaluation_challenges_fr, ys_fr))
 }
 
+fn check_cancelled(cancel: &AtomicBool) -> Result<(), CancellableError> {
+    // Relaxed is enough: no other memory is published through the flag, and once a thread has
+    // seen it set, coherence keeps its later loads from reading the older value
+    if cancel.load(Ordering::Relaxed) {
+        return Err(CancellableError::Cancelled);
+    }
+
+    Ok(())
+}
+
 fn validate_batched_input<TG1: G1>(commitments: &[TG1], proofs: &[TG1]) -> Result<(), String> {
     let invalid_commitment = cfg_into_iter!(commitments)
         .any(|commitment| !commitment.is_inf() && !commitment.is_valid());
@@ -640,12 +1429,144 @@ pub fn verify_blob_kzg_proof_batch_rust<
     TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine> + Sync,
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
+    B: AsRef<[TFr]> + Sync,
 >(
-    blobs: &[Vec<TFr>],
+    blobs: &[B],
     commitments_g1: &[TG1],
     proofs_g1: &[TG1],
     ts: &TKZGSettings,
 ) -> Result<bool, String> {
+    verify_blob_kzg_proof_batch_cancellable_rust(
+        blobs,
+        commitments_g1,
+        proofs_g1,
+        ts,
+        &AtomicBool::new(false),
+    )
+    .map_err(|err| err.to_string())
+}
+
+/// Checks blobs received with blob transactions against the `versioned_hashes` those
+/// transactions list. Returns whether every commitment hashes to its versioned hash and every
+/// proof shows that its blob matches its commitment. All lists must have the same length.
+pub fn verify_blob_bundle_rust<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + PairingVerify<TG1, TG2> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine> + Sync,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    B: AsRef<[TFr]> + Sync,
+>(
+    blobs: &[B],
+    commitments_g1: &[TG1],
+    proofs_g1: &[TG1],
+    versioned_hashes: &[[u8; 32]],
+    ts: &TKZGSettings,
+) -> Result<bool, String> {
+    let n = commitments_g1.len();
+    if blobs.len() != n || proofs_g1.len() != n || versioned_hashes.len() != n {
+        return Err("Invalid amount of arguments".to_string());
+    }
+
+    // Hashing is cheap, so a mismatch is caught before any pairing is computed
+    if commitments_g1
+        .iter()
+        .zip(versioned_hashes)
+        .any(|(commitment, versioned_hash)| kzg_to_versioned_hash(commitment) != *versioned_hash)
+    {
+        return Ok(false);
+    }
+
+    verify_blob_kzg_proof_batch_rust(blobs, commitments_g1, proofs_g1, ts)
+}
+
+/// Same as [`verify_blob_kzg_proof_batch_rust`], but returns [`CancellableError::Cancelled`]
+/// soon after `cancel` is set. The flag is checked before each blob and before each multi-scalar
+/// multiplication, so each worker thread finishes at most the step it is in.
+pub fn verify_blob_kzg_proof_batch_cancellable_rust<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + PairingVerify<TG1, TG2> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine> + Sync,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    B: AsRef<[TFr]> + Sync,
+>(
+    blobs: &[B],
+    commitments_g1: &[TG1],
+    proofs_g1: &[TG1],
+    ts: &TKZGSettings,
+    cancel: &AtomicBool,
+) -> Result<bool, CancellableError> {
+    verify_blob_kzg_proof_batch_impl(
+        blobs,
+        commitments_g1,
+        proofs_g1,
+        ts,
+        &DomainConfig::SPEC,
+        cancel,
+    )
+}
+
+/// Same as [`verify_blob_kzg_proof_batch_rust`], with the challenges prefixed by the tags in
+/// `domains`.
+pub fn verify_blob_kzg_proof_batch_with_domains_rust<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + PairingVerify<TG1, TG2> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine> + Sync,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    B: AsRef<[TFr]> + Sync,
+>(
+    blobs: &[B],
+    commitments_g1: &[TG1],
+    proofs_g1: &[TG1],
+    ts: &TKZGSettings,
+    domains: &DomainConfig,
+) -> Result<bool, String> {
+    verify_blob_kzg_proof_batch_impl(
+        blobs,
+        commitments_g1,
+        proofs_g1,
+        ts,
+        domains,
+        &AtomicBool::new(false),
+    )
+    .map_err(|err| err.to_string())
+}
+
+fn verify_blob_kzg_proof_batch_impl<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + PairingVerify<TG1, TG2> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine> + Sync,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    B: AsRef<[TFr]> + Sync,
+>(
+    blobs: &[B],
+    commitments_g1: &[TG1],
+    proofs_g1: &[TG1],
+    ts: &TKZGSettings,
+    domains: &DomainConfig,
+    cancel: &AtomicBool,
+) -> Result<bool, CancellableError> {
+    if blobs.len() != commitments_g1.len() || blobs.len() != proofs_g1.len() {
+        return Err(CancellableError::Failed(
+            "Invalid amount of arguments".to_string(),
+        ));
+    }
+
     // Exit early if we are given zero blobs
     if blobs.is_empty() {
         return Ok(true);
@@ -653,11 +1574,14 @@ pub fn verify_blob_kzg_proof_batch_rust<
 
     // For a single blob, just do a regular single verification
     if blobs.len() == 1 {
-        return verify_blob_kzg_proof_rust(&blobs[0], &commitments_g1[0], &proofs_g1[0], ts);
-    }
-
-    if blobs.len() != commitments_g1.len() || blobs.len() != proofs_g1.len() {
-        return Err("Invalid amount of arguments".to_string());
+        check_cancelled(cancel)?;
+        return Ok(verify_blob_kzg_proof_with_domains_rust(
+            blobs[0].as_ref(),
+            &commitments_g1[0],
+            &proofs_g1[0],
+            ts,
+            domains,
+        )?);
     }
 
     #[cfg(feature = "parallel")]
@@ -685,6 +1609,8 @@ pub fn verify_blob_kzg_proof_batch_rust<
                             blob_group,
                             commitment_group,
                             ts,
+                            domains,
+                            cancel,
                         )?;
 
                     verify_kzg_proof_batch(
@@ -693,6 +1619,8 @@ pub fn verify_blob_kzg_proof_batch_rust<
                         &ys_fr,
                         proof_group,
                         ts,
+                        domains,
+                        cancel,
                     )
                 })
                 .try_reduce(|| true, |a, b| Ok(a && b))
@@ -702,7 +1630,14 @@ pub fn verify_blob_kzg_proof_batch_rust<
             (blobs, commitments_g1, proofs_g1)
                 .into_par_iter()
                 .map(|(blob, commitment, proof)| {
-                    verify_blob_kzg_proof_rust(blob, commitment, proof, ts)
+                    check_cancelled(cancel)?;
+                    Ok(verify_blob_kzg_proof_with_domains_rust(
+                        blob.as_ref(),
+                        commitment,
+                        proof,
+                        ts,
+                        domains,
+                    )?)
                 })
                 .try_reduce(|| true, |a, b| Ok(a && b))
         }
@@ -712,7 +1647,7 @@ pub fn verify_blob_kzg_proof_batch_rust<
     {
         validate_batched_input(commitments_g1, proofs_g1)?;
         let (evaluation_challenges_fr, ys_fr) =
-            compute_challenges_and_evaluate_polynomial(blobs, commitments_g1, ts)?;
+            compute_challenges_and_evaluate_polynomial(blobs, commitments_g1, ts, domains, cancel)?;
 
         verify_kzg_proof_batch(
             commitments_g1,
@@ -720,10 +1655,167 @@ pub fn verify_blob_kzg_proof_batch_rust<
             &ys_fr,
             proofs_g1,
             ts,
+            domains,
+            cancel,
         )
     }
 }
 
+// Combines the blobs and commitments with powers of a challenge that binds all of them, and
+// derives the point at which the combination is opened
+fn compute_aggregated_blob_and_commitment<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    B: AsRef<[TFr]>,
+>(
+    blobs: &[B],
+    commitments_g1: &[TG1],
+    domains: &DomainConfig,
+) -> Result<(Vec<TFr>, TG1, TFr), String> {
+    if blobs.is_empty() || blobs.len() != commitments_g1.len() {
+        return Err("Invalid amount of arguments".to_string());
+    }
+    if blobs
+        .iter()
+        .any(|blob| blob.as_ref().len() != FIELD_ELEMENTS_PER_BLOB)
+    {
+        return Err(format!(
+            "Blob length must be FIELD_ELEMENTS_PER_BLOB ({})",
+            FIELD_ELEMENTS_PER_BLOB
+        ));
+    }
+
+    let mut transcript = Transcript::<DefaultDigest>::with_capacity(
+        &domains.aggregate_proof,
+        16 + blobs.len() * (BYTES_PER_BLOB + BYTES_PER_COMMITMENT),
+    );
+    transcript.append_u64(FIELD_ELEMENTS_PER_BLOB as u64);
+    transcript.append_u64(blobs.len() as u64);
+    for blob in blobs {
+        for field in blob.as_ref() {
+            transcript.append_fr(field);
+        }
+    }
+    for commitment in commitments_g1 {
+        transcript.append_g1(commitment);
+    }
+
+    let r: TFr = transcript.challenge_fr();
+    let r_powers = compute_powers(&r, blobs.len());
+    let z = r_powers[blobs.len() - 1].mul(&r);
+
+    let mut aggregated_blob = vec![TFr::zero(); FIELD_ELEMENTS_PER_BLOB];
+    for (blob, r_power) in blobs.iter().zip(&r_powers) {
+        for (aggregated, field) in aggregated_blob.iter_mut().zip(blob.as_ref()) {
+            *aggregated = aggregated.add(&field.mul(r_power));
+        }
+    }
+    let aggregated_commitment = TG1::g1_lincomb(commitments_g1, &r_powers, blobs.len(), None);
+
+    Ok((aggregated_blob, aggregated_commitment, z))
+}
+
+/// Computes a single proof for all `blobs`, verified with [`verify_aggregate_kzg_proof_rust`].
+/// It opens a random combination of the blobs, so it only shows that all of them match their
+/// commitments together and cannot be used for a subset.
+pub fn compute_aggregate_kzg_proof_rust<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    B: AsRef<[TFr]>,
+>(
+    blobs: &[B],
+    commitments_g1: &[TG1],
+    ts: &TKZGSettings,
+) -> Result<TG1, String> {
+    compute_aggregate_kzg_proof_with_domains_rust(blobs, commitments_g1, ts, &DomainConfig::SPEC)
+}
+
+/// Same as [`compute_aggregate_kzg_proof_rust`], with the challenge prefixed by the aggregate tag
+/// in `domains`.
+pub fn compute_aggregate_kzg_proof_with_domains_rust<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    B: AsRef<[TFr]>,
+>(
+    blobs: &[B],
+    commitments_g1: &[TG1],
+    ts: &TKZGSettings,
+    domains: &DomainConfig,
+) -> Result<TG1, String> {
+    validate_batched_input(commitments_g1, &[])?;
+    let (aggregated_blob, _, z) =
+        compute_aggregated_blob_and_commitment(blobs, commitments_g1, domains)?;
+
+    compute_kzg_proof_rust(&aggregated_blob, &z, ts).map(|(proof, _)| proof)
+}
+
+pub fn verify_aggregate_kzg_proof_rust<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    B: AsRef<[TFr]>,
+>(
+    blobs: &[B],
+    commitments_g1: &[TG1],
+    proof_g1: &TG1,
+    ts: &TKZGSettings,
+) -> Result<bool, String> {
+    verify_aggregate_kzg_proof_with_domains_rust(
+        blobs,
+        commitments_g1,
+        proof_g1,
+        ts,
+        &DomainConfig::SPEC,
+    )
+}
+
+/// Same as [`verify_aggregate_kzg_proof_rust`], with the challenge prefixed by the aggregate tag
+/// in `domains`.
+pub fn verify_aggregate_kzg_proof_with_domains_rust<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    B: AsRef<[TFr]>,
+>(
+    blobs: &[B],
+    commitments_g1: &[TG1],
+    proof_g1: &TG1,
+    ts: &TKZGSettings,
+    domains: &DomainConfig,
+) -> Result<bool, String> {
+    validate_batched_input(commitments_g1, core::slice::from_ref(proof_g1))?;
+    let (aggregated_blob, aggregated_commitment, z) =
+        compute_aggregated_blob_and_commitment(blobs, commitments_g1, domains)?;
+
+    let y = evaluate_blob_in_evaluation_form(&aggregated_blob, &z, ts)?;
+
+    verify_kzg_proof_rust(&aggregated_commitment, &z, &y, proof_g1, ts)
+}
+
 #[allow(clippy::useless_conversion)]
 pub fn bytes_to_blob<TFr: Fr>(bytes: &[u8]) -> Result<Vec<TFr>, String> {
     if bytes.len() != BYTES_PER_BLOB {
@@ -734,10 +1826,9 @@ pub fn bytes_to_blob<TFr: Fr>(bytes: &[u8]) -> Result<Vec<TFr>, String> {
         ));
     }
 
-    bytes
-        .chunks(BYTES_PER_FIELD_ELEMENT)
-        .map(TFr::from_bytes)
-        .collect()
+    let mut blob = vec![TFr::default(); FIELD_ELEMENTS_PER_BLOB];
+    TFr::from_bytes_batch(bytes, &mut blob)?;
+    Ok(blob)
 }
 
 fn fr_batch_inv<TFr: Fr + PartialEq + Copy>(
@@ -774,41 +1865,53 @@ fn fr_batch_inv<TFr: Fr + PartialEq + Copy>(
     Ok(())
 }
 
+// The spec defines this as a plain modular reduction of the digest. It intentionally does not go
+// through `Fr::hash_to_field`, as that would change every Fiat-Shamir challenge.
 pub fn hash_to_bls_field<TFr: Fr>(x: &[u8; BYTES_PER_FIELD_ELEMENT]) -> TFr {
     TFr::from_bytes_unchecked(x).unwrap()
 }
 
-fn compute_challenge<TFr: Fr, TG1: G1>(blob: &[TFr], commitment: &TG1) -> TFr {
-    let mut bytes: Vec<u8> = vec![0; CHALLENGE_INPUT_SIZE];
-
-    // Copy domain separator
-    bytes[..16].copy_from_slice(&FIAT_SHAMIR_PROTOCOL_DOMAIN);
-    // Set all other bytes of this 16-byte (big-endian) field to zero
-    bytes_of_uint64(&mut bytes[16..24], 0);
-    bytes_of_uint64(&mut bytes[24..32], FIELD_ELEMENTS_PER_BLOB as u64);
-
-    for (i, field) in blob.iter().enumerate() {
-        let v = field.to_bytes();
-        let size = (32 + i * BYTES_PER_FIELD_ELEMENT)..(32 + (i + 1) * BYTES_PER_FIELD_ELEMENT);
-
-        bytes[size].copy_from_slice(&v);
+fn compute_challenge<TFr: Fr, TG1: G1>(
+    blob: &[TFr],
+    commitment: &TG1,
+    domains: &DomainConfig,
+) -> TFr {
+    let mut transcript = challenge_transcript(domains);
+    for field in blob {
+        transcript.append_fr(field);
     }
+    transcript.append_g1(commitment);
 
-    // Copy commitment
-    let v = commitment.to_bytes();
-    for i in 0..v.len() {
-        bytes[32 + BYTES_PER_BLOB + i] = v[i];
-    }
+    transcript.challenge_fr()
+}
 
-    // Now let's create the challenge!
-    let eval_challenge = hash(&bytes);
-    hash_to_bls_field(&eval_challenge)
+// Starts the transcript of a blob's challenge, which goes on with the blob and its commitment
+fn challenge_transcript(domains: &DomainConfig) -> Transcript<DefaultDigest> {
+    let mut transcript = Transcript::<DefaultDigest>::with_capacity(
+        &domains.fiat_shamir_protocol,
+        CHALLENGE_INPUT_SIZE - 16,
+    );
+    // The degree is a 16-byte big-endian field, so its upper half is zero
+    transcript.append_u64(0);
+    transcript.append_u64(FIELD_ELEMENTS_PER_BLOB as u64);
+    transcript
 }
 
-pub fn blob_to_polynomial<TFr: Fr, TPoly: Poly<TFr>>(blob: &[TFr]) -> Result<TPoly, String> {
+fn check_blob_length<TFr>(blob: &[TFr]) -> Result<(), String> {
     if blob.len() != FIELD_ELEMENTS_PER_BLOB {
-        return Err(String::from("Blob length must be FIELD_ELEMENTS_PER_BLOB"));
+        return Err(format!(
+            "Blob length must be FIELD_ELEMENTS_PER_BLOB ({}), got {}",
+            FIELD_ELEMENTS_PER_BLOB,
+            blob.len()
+        ));
     }
+    Ok(())
+}
+
+/// Copies the blob into a polynomial. The functions in this module work on the blob directly,
+/// so this is only needed to use the blob with the [`Poly`] API.
+pub fn blob_to_polynomial<TFr: Fr, TPoly: Poly<TFr>>(blob: &[TFr]) -> Result<TPoly, String> {
+    check_blob_length(blob)?;
     Ok(TPoly::from_coeffs(blob))
 }
 
@@ -826,7 +1929,25 @@ pub fn evaluate_polynomial_in_evaluation_form<
     x: &TFr,
     s: &TKZGSettings,
 ) -> Result<TFr, String> {
-    if p.len() != FIELD_ELEMENTS_PER_BLOB {
+    evaluate_blob_in_evaluation_form(p.get_coeffs(), x, s)
+}
+
+/// Evaluates the polynomial given by its evaluations over the domain, such as a blob, at `x`.
+pub fn evaluate_blob_in_evaluation_form<
+    TFr: Fr + Copy,
+    TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    poly_coeffs: &[TFr],
+    x: &TFr,
+    s: &TKZGSettings,
+) -> Result<TFr, String> {
+    if poly_coeffs.len() != FIELD_ELEMENTS_PER_BLOB {
         return Err(String::from("Incorrect field elements count."));
     }
 
@@ -834,7 +1955,6 @@ pub fn evaluate_polynomial_in_evaluation_form<
     let mut inverses: Vec<TFr> = vec![TFr::default(); FIELD_ELEMENTS_PER_BLOB];
 
     let roots_of_unity = s.get_fft_settings().get_roots_of_unity();
-    let poly_coeffs = p.get_coeffs();
 
     for i in 0..FIELD_ELEMENTS_PER_BLOB {
         if x == &roots_of_unity[i] {
@@ -893,13 +2013,35 @@ pub fn load_trusted_setup_rust<
     g2_bytes: &[u8],
 ) -> Result<TKZGSettings, String> {
     let num_g1_points = g1_bytes.len() / BYTES_PER_G1;
-    if num_g1_points != FIELD_ELEMENTS_PER_BLOB {
-        return Err(String::from("Invalid number of G1 points"));
+    if num_g1_points * BYTES_PER_G1 != g1_bytes.len() {
+        return Err(format!(
+            "G1 bytes length must be a multiple of {}, got {}",
+            BYTES_PER_G1,
+            g1_bytes.len()
+        ));
     }
 
     let num_g2_points = g2_bytes.len() / BYTES_PER_G2;
+    if num_g2_points * BYTES_PER_G2 != g2_bytes.len() {
+        return Err(format!(
+            "G2 bytes length must be a multiple of {}, got {}",
+            BYTES_PER_G2,
+            g2_bytes.len()
+        ));
+    }
+
+    if num_g1_points != FIELD_ELEMENTS_PER_BLOB {
+        return Err(format!(
+            "Invalid number of G1 points. Expected {}, got {}",
+            FIELD_ELEMENTS_PER_BLOB, num_g1_points
+        ));
+    }
+
     if num_g2_points != TRUSTED_SETUP_NUM_G2_POINTS {
-        return Err(String::from("Invalid number of G2 points"));
+        return Err(format!(
+            "Invalid number of G2 points. Expected {}, got {}",
+            TRUSTED_SETUP_NUM_G2_POINTS, num_g2_points
+        ));
     }
 
     let mut g1_values = g1_bytes
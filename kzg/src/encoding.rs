@@ -0,0 +1,96 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::eip_4844::{
+    bytes_to_blob, BYTES_PER_BLOB, BYTES_PER_FIELD_ELEMENT, FIELD_ELEMENTS_PER_BLOB,
+};
+use crate::Fr;
+
+pub const USABLE_BYTES_PER_FIELD_ELEMENT: usize = BYTES_PER_FIELD_ELEMENT - 1;
+
+const LENGTH_PREFIX_BYTES: usize = 4;
+
+/// Largest amount of data that fits in one blob.
+pub const MAX_DATA_BYTES_PER_BLOB: usize =
+    USABLE_BYTES_PER_FIELD_ELEMENT * FIELD_ELEMENTS_PER_BLOB - LENGTH_PREFIX_BYTES;
+
+/// Encodes `data` into the bytes of a blob: its length as 4 big-endian bytes, the data, then zero
+/// padding, packed 31 bytes per field element behind a zero first byte.
+pub fn encode_data(data: &[u8]) -> Result<Vec<u8>, String> {
+    if data.len() > MAX_DATA_BYTES_PER_BLOB {
+        return Err(format!(
+            "Data has {} bytes, but a blob holds at most {}",
+            data.len(),
+            MAX_DATA_BYTES_PER_BLOB
+        ));
+    }
+
+    let mut payload = Vec::with_capacity(LENGTH_PREFIX_BYTES + data.len());
+    payload.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    payload.extend_from_slice(data);
+
+    let mut blob = vec![0u8; BYTES_PER_BLOB];
+    for (element, chunk) in blob
+        .chunks_exact_mut(BYTES_PER_FIELD_ELEMENT)
+        .zip(payload.chunks(USABLE_BYTES_PER_FIELD_ELEMENT))
+    {
+        element[1..1 + chunk.len()].copy_from_slice(chunk);
+    }
+
+    Ok(blob)
+}
+
+/// Decodes the bytes of a blob produced by [`encode_data`]. Any other layout is rejected.
+pub fn decode_data(blob: &[u8]) -> Result<Vec<u8>, String> {
+    if blob.len() != BYTES_PER_BLOB {
+        return Err(format!(
+            "Invalid byte length. Expected {} got {}",
+            BYTES_PER_BLOB,
+            blob.len(),
+        ));
+    }
+
+    let mut payload = Vec::with_capacity(USABLE_BYTES_PER_FIELD_ELEMENT * FIELD_ELEMENTS_PER_BLOB);
+    for (i, element) in blob.chunks_exact(BYTES_PER_FIELD_ELEMENT).enumerate() {
+        if element[0] != 0 {
+            return Err(format!("Field element {} has a non-zero first byte", i));
+        }
+        payload.extend_from_slice(&element[1..]);
+    }
+
+    let mut length = [0u8; LENGTH_PREFIX_BYTES];
+    length.copy_from_slice(&payload[..LENGTH_PREFIX_BYTES]);
+    let length = u32::from_be_bytes(length) as usize;
+    if length > MAX_DATA_BYTES_PER_BLOB {
+        return Err(format!(
+            "Encoded length {} exceeds the maximum of {}",
+            length, MAX_DATA_BYTES_PER_BLOB
+        ));
+    }
+
+    let end = LENGTH_PREFIX_BYTES + length;
+    if payload[end..].iter().any(|&byte| byte != 0) {
+        return Err(String::from("Padding after the data is not zero"));
+    }
+
+    payload.truncate(end);
+    payload.drain(..LENGTH_PREFIX_BYTES);
+    Ok(payload)
+}
+
+/// Encodes `data` into a blob of field elements.
+pub fn data_to_blob<TFr: Fr>(data: &[u8]) -> Result<Vec<TFr>, String> {
+    bytes_to_blob(&encode_data(data)?)
+}
+
+/// Decodes a blob of field elements produced by [`data_to_blob`].
+pub fn blob_to_data<TFr: Fr>(blob: &[TFr]) -> Result<Vec<u8>, String> {
+    let mut bytes = Vec::with_capacity(BYTES_PER_BLOB);
+    for element in blob {
+        bytes.extend_from_slice(&element.to_bytes());
+    }
+
+    decode_data(&bytes)
+}
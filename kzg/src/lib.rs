@@ -2,16 +2,41 @@
 
 extern crate alloc;
 
+use alloc::format;
 use alloc::string::String;
 use alloc::vec::Vec;
 use core::fmt::Debug;
 use msm::precompute::PrecomputationTable;
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+pub mod blob;
+#[cfg(feature = "commitment-cache")]
+pub mod commitment_cache;
 pub mod common_utils;
+#[cfg(feature = "counting")]
+pub mod counting;
+#[cfg(feature = "async")]
+pub mod das_async;
+pub mod das_sampling;
+pub mod digest;
 pub mod eip_4844;
+pub mod encoding;
 pub mod msm;
-
-pub trait Fr: Default + Clone + PartialEq + Sync {
+pub mod sidecar;
+#[cfg(feature = "ssz")]
+pub mod ssz;
+pub mod transcript;
+
+/// A scalar of the BLS12-381 field.
+///
+/// `Send` is required like for [`G1`] and [`G1Fp`], so batch operations such as
+/// [`Fr::from_bytes_batch`] can write elements from worker threads under the `parallel`
+/// feature. A bound on those methods alone would have to be repeated on every generic caller,
+/// down to `bytes_to_blob`. Field elements are plain values, so a `Sync` implementation is in
+/// practice `Send` as well.
+pub trait Fr: Default + Clone + PartialEq + Sync + Send {
     fn null() -> Self;
 
     fn zero() -> Self;
@@ -27,14 +52,79 @@ pub trait Fr: Default + Clone + PartialEq + Sync {
         Self::from_bytes(bytes)
     }
 
+    // Parses consecutive 32-byte elements into `out`. The error names the lowest index that
+    // fails to parse, also when the elements are parsed in parallel
+    fn from_bytes_batch(bytes: &[u8], out: &mut [Self]) -> Result<(), String> {
+        if bytes.len() != out.len() * 32 {
+            return Err(format!(
+                "Invalid byte length. Expected {}, got {}",
+                out.len() * 32,
+                bytes.len()
+            ));
+        }
+
+        let parse = |(i, (chunk, fr)): (usize, (&[u8], &mut Self))| {
+            Self::from_bytes(chunk)
+                .map(|parsed| *fr = parsed)
+                .map_err(|err| (i, err))
+        };
+
+        #[cfg(feature = "parallel")]
+        let first_error = bytes
+            .par_chunks(32)
+            .zip(out.par_iter_mut())
+            .enumerate()
+            .filter_map(|item| parse(item).err())
+            .min_by_key(|(i, _)| *i);
+
+        #[cfg(not(feature = "parallel"))]
+        let first_error = bytes
+            .chunks(32)
+            .zip(out.iter_mut())
+            .enumerate()
+            .find_map(|item| parse(item).err());
+
+        match first_error {
+            Some((i, err)) => Err(format!("Invalid field element at index {}: {}", i, err)),
+            None => Ok(()),
+        }
+    }
+
     fn from_hex(hex: &str) -> Result<Self, String>;
 
+    // RFC 9380 hash_to_field with SHA-256 expand_message_xmd, L = 64 and m = 1
+    fn hash_to_field(msg: &[u8], dst: &[u8]) -> Self {
+        let uniform_bytes = common_utils::expand_message_xmd(msg, dst, 64).unwrap();
+
+        // Reduce the 512-bit big-endian integer one 64-bit limb at a time
+        let two_pow_64 = Self::from_u64_arr(&[0, 1, 0, 0]);
+        uniform_bytes
+            .chunks_exact(8)
+            .fold(Self::zero(), |acc, limb| {
+                let limb = u64::from_be_bytes(limb.try_into().unwrap());
+                acc.mul(&two_pow_64).add(&Self::from_u64(limb))
+            })
+    }
+
     fn from_u64_arr(u: &[u64; 4]) -> Self;
 
     fn from_u64(u: u64) -> Self;
 
     fn to_bytes(&self) -> [u8; 32];
 
+    // Serializes into a caller buffer of exactly 32 bytes, e.g. a slot of a larger output
+    fn write_bytes(&self, out: &mut [u8]) -> Result<(), String> {
+        if out.len() != 32 {
+            return Err(format!(
+                "Invalid output length. Expected {}, got {}",
+                32,
+                out.len()
+            ));
+        }
+        out.copy_from_slice(&self.to_bytes());
+        Ok(())
+    }
+
     fn to_u64_arr(&self) -> [u64; 4];
 
     fn is_one(&self) -> bool;
@@ -61,6 +151,36 @@ pub trait Fr: Default + Clone + PartialEq + Sync {
 
     fn div(&self, b: &Self) -> Result<Self, String>;
 
+    fn sum_slice(elems: &[Self]) -> Self {
+        elems.iter().fold(Self::zero(), |acc, x| acc.add(x))
+    }
+
+    // Backends with vectorized field arithmetic can override this
+    fn dot_product(a: &[Self], b: &[Self]) -> Result<Self, String> {
+        if a.len() != b.len() {
+            return Err(format!(
+                "Dot product operands must have equal lengths, got {} and {}",
+                a.len(),
+                b.len()
+            ));
+        }
+
+        #[cfg(feature = "parallel")]
+        let sum = a
+            .par_iter()
+            .zip(b)
+            .map(|(x, y)| x.mul(y))
+            .reduce(Self::zero, |acc, xy| acc.add(&xy));
+
+        #[cfg(not(feature = "parallel"))]
+        let sum = a
+            .iter()
+            .zip(b)
+            .fold(Self::zero(), |acc, (x, y)| acc.add(&x.mul(y)));
+
+        Ok(sum)
+    }
+
     fn equals(&self, b: &Self) -> bool;
 
     fn eq(&self, other: &Self) -> bool {
@@ -88,6 +208,19 @@ pub trait G1: Clone + Default + PartialEq + Sync + Debug + Send {
 
     fn to_bytes(&self) -> [u8; 48];
 
+    // Serializes into a caller buffer of exactly 48 bytes, e.g. a slot of a larger output
+    fn write_bytes(&self, out: &mut [u8]) -> Result<(), String> {
+        if out.len() != 48 {
+            return Err(format!(
+                "Invalid output length. Expected {}, got {}",
+                48,
+                out.len()
+            ));
+        }
+        out.copy_from_slice(&self.to_bytes());
+        Ok(())
+    }
+
     fn add_or_dbl(&self, b: &Self) -> Self;
 
     fn is_inf(&self) -> bool;
@@ -228,6 +361,27 @@ pub trait G1Affine<TG1: G1, TG1Fp: G1Fp>:
 
     fn to_proj(&self) -> TG1;
 
+    // Multi-scalar multiplication over the first `n` points, which are already in affine form.
+    // Returns an error if there are fewer than `n` points or scalars
+    fn batch_scalar_mul<TFr: Fr>(points: &[Self], scalars: &[TFr], n: usize) -> Result<TG1, String>
+    where
+        TG1: G1GetFp<TG1Fp> + G1Mul<TFr>,
+    {
+        if n > points.len() || n > scalars.len() {
+            return Err(format!(
+                "Expected at least {} points and scalars, got {} and {}",
+                n,
+                points.len(),
+                scalars.len()
+            ));
+        }
+
+        Ok(msm::msm_impls::affine_msm::<TFr, TG1, TG1Fp, Self>(
+            &points[0..n],
+            &scalars[0..n],
+        ))
+    }
+
     // Return field X of Affine
     fn x(&self) -> &TG1Fp;
 
@@ -421,18 +575,51 @@ pub trait ZeroPoly<Coeff: Fr, Polynomial: Poly<Coeff>> {
 pub trait FFTSettings<Coeff: Fr>: Default + Clone {
     fn new(scale: usize) -> Result<Self, String>;
 
+    // Same as `new`, but over the domain generated by `root_of_unity`, which must have order
+    // exactly 2^scale. By default only the root `new` already uses is accepted
+    fn new_with_root(scale: usize, root_of_unity: &Coeff) -> Result<Self, String> {
+        let settings = Self::new(scale)?;
+        if !settings
+            .get_expanded_roots_of_unity_at(1)
+            .equals(root_of_unity)
+        {
+            return Err(String::from(
+                "Only the default root of unity is supported for this scale",
+            ));
+        }
+
+        Ok(settings)
+    }
+
     fn get_max_width(&self) -> usize;
 
+    // Natural order, with `get_max_width() + 1` roots: the last one wraps around to 1
     fn get_expanded_roots_of_unity_at(&self, i: usize) -> Coeff;
 
     fn get_expanded_roots_of_unity(&self) -> &[Coeff];
 
+    // Expanded roots in reverse order, also `get_max_width() + 1` of them
     fn get_reverse_roots_of_unity_at(&self, i: usize) -> Coeff;
 
     fn get_reversed_roots_of_unity(&self) -> &[Coeff];
 
+    // Bit-reversed order, with `get_max_width()` roots. Index `get_max_width()` wraps around to
+    // the root at index 0, which is 1; larger indices panic
     fn get_roots_of_unity_at(&self, i: usize) -> Coeff;
 
+    // Same as `get_roots_of_unity_at`, but returns an error for indices past `get_max_width()`
+    fn try_get_roots_of_unity_at(&self, i: usize) -> Result<Coeff, String> {
+        let width = self.get_max_width();
+        if i > width {
+            return Err(format!(
+                "Root of unity index {} is out of bounds for width {}",
+                i, width
+            ));
+        }
+
+        Ok(self.get_roots_of_unity_at(i))
+    }
+
     fn get_roots_of_unity(&self) -> &[Coeff];
 }
 
@@ -486,6 +673,69 @@ pub trait Poly<Coeff: Fr>: Default + Clone {
     fn fast_div(&mut self, x: &Self) -> Result<Self, String>;
 
     fn mul_direct(&mut self, x: &Self, len: usize) -> Result<Self, String>;
+
+    /// Multiplies by `x`, keeping the lowest `len` coefficients of the product. Backends with
+    /// FFT-based multiplication should override this to use it for large inputs.
+    fn mul(&mut self, x: &Self, len: usize) -> Result<Self, String> {
+        self.mul_direct(x, len)
+    }
+
+    /// Divides by `x`, returning the quotient and a remainder of exactly `x.len() - 1`
+    /// coefficients.
+    fn div_rem(&mut self, x: &Self) -> Result<(Self, Self), String> {
+        if x.is_empty() {
+            return Err(String::from("Can't divide by zero"));
+        } else if x.get_coeff_at(x.len() - 1).is_zero() {
+            return Err(String::from("Highest coefficient must be non-zero"));
+        }
+
+        let mut quotient = self.div(x)?;
+        let rem_len = x.len() - 1;
+
+        // Only the low coefficients of quotient * x are needed, the rest cancel out
+        let product = if quotient.is_empty() {
+            Self::new(0)
+        } else {
+            quotient.mul(x, rem_len)?
+        };
+
+        let mut remainder = Self::new(rem_len);
+        for i in 0..rem_len {
+            let lhs = if i < self.len() {
+                self.get_coeff_at(i)
+            } else {
+                Coeff::zero()
+            };
+            let rhs = if i < product.len() {
+                product.get_coeff_at(i)
+            } else {
+                Coeff::zero()
+            };
+            remainder.set_coeff_at(i, &lhs.sub(&rhs));
+        }
+
+        Ok((quotient, remainder))
+    }
+
+    /// Returns a copy resized to `new_len` coefficients, truncating or padding with zeroes.
+    fn pad(&self, new_len: usize) -> Self {
+        let coeffs = self.get_coeffs();
+        let mut padded = coeffs[..coeffs.len().min(new_len)].to_vec();
+        padded.resize(new_len, Coeff::zero());
+
+        Self::from_coeffs(&padded)
+    }
+
+    /// Returns a copy with every coefficient multiplied by `factor`.
+    fn scale_by(&self, factor: &Coeff) -> Self {
+        let scaled = self
+            .get_coeffs()
+            .iter()
+            .map(|coeff| coeff.mul(factor))
+            .collect::<Vec<_>>();
+
+        Self::from_coeffs(&scaled)
+    }
 }
 
 pub trait PolyRecover<Coeff: Fr, Polynomial: Poly<Coeff>, FSettings: FFTSettings<Coeff>> {
@@ -517,8 +767,23 @@ pub trait KZGSettings<
         fs: &Fs,
     ) -> Result<Self, String>;
 
+    // Builds settings over the very same G1 and G2 setup storage as `base`; only the MSM
+    // precomputation is built anew, and only when requested and `base` has none to share.
+    // Backends whose settings cannot share their setup storage return an error
+    fn with_shared_points(_base: &Self, _with_precomputation: bool) -> Result<Self, String> {
+        Err(String::from(
+            "Sharing setup points is not supported by this backend",
+        ))
+    }
+
     fn commit_to_poly(&self, p: &Polynomial) -> Result<Coeff2, String>;
 
+    // Commits to monomial coefficients, which must not outnumber the G1 setup points
+    fn commit_monomial(&self, coeffs: &[Coeff1]) -> Result<Coeff2, String> {
+        common_utils::check_degree_bound(coeffs.len(), self.get_g1_secret().len())?;
+        self.commit_to_poly(&Polynomial::from_coeffs(coeffs))
+    }
+
     fn compute_proof_single(&self, p: &Polynomial, x: &Coeff1) -> Result<Coeff2, String>;
 
     fn check_proof_single(
@@ -550,7 +815,25 @@ pub trait KZGSettings<
 
     fn get_g2_secret(&self) -> &[Coeff3];
 
+    // Backends that keep the generator alongside the G2 setup can return it without recomputing
+    fn get_g2_generator(&self) -> Coeff3 {
+        Coeff3::generator()
+    }
+
     fn get_precomputation(&self) -> Option<&PrecomputationTable<Coeff1, Coeff2, TG1Fp, TG1Affine>>;
+
+    // A blob holds one field element per G1 point of the setup
+    fn get_field_elements_per_blob(&self) -> usize {
+        self.get_g1_secret().len()
+    }
+
+    fn get_field_elements_per_ext_blob(&self) -> usize {
+        2 * self.get_field_elements_per_blob()
+    }
+
+    fn get_scale(&self) -> usize {
+        common_utils::log_2(self.get_field_elements_per_ext_blob())
+    }
 }
 
 pub trait FK20SingleSettings<
@@ -77,6 +77,33 @@ pub fn batch_convert<TG1: G1, TFp: G1Fp, TG1Affine: G1Affine<TG1, TFp> + Sized>(
     return TG1Affine::into_affines(points);
 }
 
+pub fn affine_msm<
+    TFr: Fr,
+    TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    points: &[TG1Affine],
+    scalars: &[TFr],
+) -> TG1 {
+    if points.len() < 8 {
+        let mut out = TG1::default();
+        for (point, scalar) in points.iter().zip(scalars) {
+            let tmp = point.to_proj().mul(scalar);
+            out.add_or_dbl_assign(&tmp);
+        }
+        return out;
+    }
+
+    let scalars = scalars.iter().map(TFr::to_scalar).collect::<Vec<_>>();
+
+    #[cfg(feature = "parallel")]
+    return tiling_parallel_pippenger(points, &scalars);
+
+    #[cfg(not(feature = "parallel"))]
+    return super::tiling_pippenger_ops::tiling_pippenger(points, &scalars);
+}
+
 #[allow(clippy::extra_unused_type_parameters)]
 pub fn msm<
     TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
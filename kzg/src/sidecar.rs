@@ -0,0 +1,67 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::blob::Blob;
+use crate::cfg_into_iter;
+use crate::eip_4844::{compute_blob_kzg_proof_rust, kzg_to_versioned_hash};
+use crate::{
+    FFTSettings, Fr, G1Affine, G1Fp, G1GetFp, G1LinComb, G1Mul, KZGSettings, Poly, G1, G2,
+};
+
+/// KZG part of a blob sidecar. The block header and the commitment inclusion proof are left to
+/// the caller.
+pub struct BlobSidecar<TFr, TG1> {
+    /// Position of the blob in the block.
+    pub index: u64,
+    pub blob: Blob<TFr>,
+    pub kzg_commitment: TG1,
+    pub kzg_proof: TG1,
+    /// Hash referencing the commitment from the blob transaction.
+    pub versioned_hash: [u8; 32],
+}
+
+/// Computes the proofs and versioned hashes of `blobs` and bundles each blob with them.
+/// `commitments` must hold the commitment of every blob, in the same order.
+pub fn build_blob_sidecars<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine> + Sync,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    B: Into<Vec<TFr>> + Send,
+>(
+    blobs: Vec<B>,
+    commitments: &[TG1],
+    ts: &TKZGSettings,
+) -> Result<Vec<BlobSidecar<TFr, TG1>>, String> {
+    if blobs.len() != commitments.len() {
+        return Err(format!(
+            "Got {} blobs, but {} commitments",
+            blobs.len(),
+            commitments.len()
+        ));
+    }
+
+    cfg_into_iter!(blobs)
+        .zip(commitments)
+        .enumerate()
+        .map(|(index, (blob, commitment))| {
+            let blob = Blob::new(blob.into())?;
+            let kzg_proof = compute_blob_kzg_proof_rust(&blob, commitment, ts)?;
+            Ok(BlobSidecar {
+                index: index as u64,
+                blob,
+                kzg_commitment: commitment.clone(),
+                kzg_proof,
+                versioned_hash: kzg_to_versioned_hash(commitment),
+            })
+        })
+        .collect()
+}
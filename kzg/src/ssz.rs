@@ -0,0 +1,86 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::eip_4844::{bytes_to_blob, BYTES_PER_BLOB, BYTES_PER_G1, FIELD_ELEMENTS_PER_BLOB};
+use crate::{Fr, G1};
+
+/// Length limit of the commitment and proof lists in blocks and sidecars.
+pub const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize = 4096;
+
+pub fn blob_to_ssz<TFr: Fr>(blob: &[TFr]) -> Result<Vec<u8>, String> {
+    if blob.len() != FIELD_ELEMENTS_PER_BLOB {
+        return Err(format!(
+            "Blob length must be FIELD_ELEMENTS_PER_BLOB ({}), got {}",
+            FIELD_ELEMENTS_PER_BLOB,
+            blob.len()
+        ));
+    }
+
+    let mut bytes = Vec::with_capacity(BYTES_PER_BLOB);
+    for element in blob {
+        bytes.extend_from_slice(&element.to_bytes());
+    }
+    Ok(bytes)
+}
+
+pub fn blob_from_ssz<TFr: Fr>(bytes: &[u8]) -> Result<Vec<TFr>, String> {
+    bytes_to_blob(bytes)
+}
+
+/// Encodes a commitment or a proof.
+pub fn g1_to_ssz<TG1: G1>(point: &TG1) -> [u8; BYTES_PER_G1] {
+    point.to_bytes()
+}
+
+/// Decodes a commitment or a proof.
+pub fn g1_from_ssz<TG1: G1>(bytes: &[u8]) -> Result<TG1, String> {
+    if bytes.len() != BYTES_PER_G1 {
+        return Err(format!(
+            "Invalid byte length. Expected {} got {}",
+            BYTES_PER_G1,
+            bytes.len()
+        ));
+    }
+
+    TG1::from_bytes(bytes)
+}
+
+/// Encodes a list of commitments or proofs holding at most `max_len` items, as the concatenation
+/// of the items.
+pub fn g1_list_to_ssz<TG1: G1>(points: &[TG1], max_len: usize) -> Result<Vec<u8>, String> {
+    if points.len() > max_len {
+        return Err(format!(
+            "List has {} items, but at most {} are allowed",
+            points.len(),
+            max_len
+        ));
+    }
+
+    let mut bytes = Vec::with_capacity(points.len() * BYTES_PER_G1);
+    for point in points {
+        bytes.extend_from_slice(&g1_to_ssz(point));
+    }
+    Ok(bytes)
+}
+
+/// Decodes a list of commitments or proofs holding at most `max_len` items.
+pub fn g1_list_from_ssz<TG1: G1>(bytes: &[u8], max_len: usize) -> Result<Vec<TG1>, String> {
+    let items = bytes.chunks_exact(BYTES_PER_G1);
+    if !items.remainder().is_empty() {
+        return Err(format!(
+            "List byte length {} is not a multiple of {}",
+            bytes.len(),
+            BYTES_PER_G1
+        ));
+    }
+    if items.len() > max_len {
+        return Err(format!(
+            "List has {} items, but at most {} are allowed",
+            items.len(),
+            max_len
+        ));
+    }
+
+    items.map(g1_from_ssz).collect()
+}
@@ -0,0 +1,63 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crate::digest::{DefaultDigest, Digest};
+use crate::eip_4844::hash_to_bls_field;
+use crate::{Fr, G1};
+
+/// Fiat-Shamir transcript: a domain tag followed by everything the challenge is derived from.
+pub struct Transcript<D: Digest = DefaultDigest> {
+    bytes: Vec<u8>,
+    digest: PhantomData<D>,
+}
+
+impl<D: Digest> Transcript<D> {
+    pub fn new(domain: &[u8; 16]) -> Self {
+        Self::with_capacity(domain, 0)
+    }
+
+    /// Reserves room for `capacity` bytes after the domain.
+    pub fn with_capacity(domain: &[u8; 16], capacity: usize) -> Self {
+        let mut bytes = Vec::with_capacity(domain.len() + capacity);
+        bytes.extend_from_slice(domain);
+        Self {
+            bytes,
+            digest: PhantomData,
+        }
+    }
+
+    pub fn append_bytes(&mut self, bytes: &[u8]) {
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    /// Appends `n` as 8 big-endian bytes.
+    pub fn append_u64(&mut self, n: u64) {
+        self.bytes.extend_from_slice(&n.to_be_bytes());
+    }
+
+    pub fn append_fr<TFr: Fr>(&mut self, fr: &TFr) {
+        self.bytes.extend_from_slice(&fr.to_bytes());
+    }
+
+    pub fn append_g1<TG1: G1>(&mut self, g1: &TG1) {
+        self.bytes.extend_from_slice(&g1.to_bytes());
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn challenge_fr<TFr: Fr>(&self) -> TFr {
+        hash_to_bls_field(&D::digest(&self.bytes))
+    }
+
+    /// Challenge over the transcript followed by `counter`, used to re-derive a challenge that
+    /// turned out to be unusable.
+    pub fn challenge_fr_with_counter<TFr: Fr>(&self, counter: u8) -> TFr {
+        let digest = D::default()
+            .chain_update(&self.bytes)
+            .chain_update(&[counter])
+            .finalize();
+        hash_to_bls_field(&digest)
+    }
+}
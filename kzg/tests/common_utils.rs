@@ -1,6 +1,6 @@
 #[cfg(test)]
 pub mod tests {
-    use kzg::common_utils::reverse_bit_order;
+    use kzg::common_utils::{expand_message_xmd, reverse_bit_order};
 
     #[test]
     fn reverse_bit_order_bad_arguments() {
@@ -13,4 +13,54 @@ pub mod tests {
         // array with 4 elements should pass
         assert!(reverse_bit_order(&mut [1u8, 2u8, 3u8, 4u8]).is_ok());
     }
+
+    #[test]
+    fn reverse_bit_order_error_reports_length() {
+        let err = reverse_bit_order(&mut [0u8; 6]).unwrap_err();
+        assert!(err.contains('6'));
+    }
+
+    // Test vectors from RFC 9380, appendix K.1
+    const XMD_SHA256_DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHA256-128";
+
+    #[test]
+    fn expand_message_xmd_sha256_short_output() {
+        let out = expand_message_xmd(b"", XMD_SHA256_DST, 0x20).unwrap();
+        assert_eq!(
+            hex::encode(out),
+            "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"
+        );
+
+        let out = expand_message_xmd(b"abc", XMD_SHA256_DST, 0x20).unwrap();
+        assert_eq!(
+            hex::encode(out),
+            "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"
+        );
+    }
+
+    #[test]
+    fn expand_message_xmd_sha256_long_output() {
+        let out = expand_message_xmd(b"", XMD_SHA256_DST, 0x80).unwrap();
+        assert_eq!(
+            hex::encode(out),
+            "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbe\
+             e0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18\
+             eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dc\
+             c541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced"
+        );
+
+        let out = expand_message_xmd(b"abc", XMD_SHA256_DST, 0x80).unwrap();
+        assert_eq!(
+            hex::encode(out),
+            "abba86a6129e366fc877aab32fc4ffc70120d8996c88aee2fe4b32d6c7b6437a\
+             647e6c3163d40b76a73cf6a5674ef1d890f95b664ee0afa5359a5c4e07985635\
+             bbecbac65d747d3d2da7ec2b8221b17b0ca9dc8a1ac1c07ea6a1e60583e2cb00\
+             058e77b7b72a298425cd1b941ad4ec65e8afc50303a22c0f99b0509b4c895f40"
+        );
+    }
+
+    #[test]
+    fn expand_message_xmd_rejects_oversized_output() {
+        assert!(expand_message_xmd(b"abc", XMD_SHA256_DST, 255 * 32 + 1).is_err());
+    }
 }
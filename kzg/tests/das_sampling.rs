@@ -0,0 +1,145 @@
+#[cfg(test)]
+pub mod tests {
+    use kzg::das_sampling::{
+        columns_for_custody_groups, compute_columns_for_custody_group, get_custody_columns,
+        get_custody_groups, get_sample_columns, CUSTODY_REQUIREMENT, NUMBER_OF_COLUMNS,
+        NUMBER_OF_CUSTODY_GROUPS, SAMPLES_PER_SLOT,
+    };
+
+    #[test]
+    fn custody_groups_are_distinct_and_sorted() {
+        let node_id = [0xabu8; 32];
+        for count in [0, 1, CUSTODY_REQUIREMENT, 64, NUMBER_OF_CUSTODY_GROUPS - 1] {
+            let groups = get_custody_groups(&node_id, count).unwrap();
+            assert_eq!(groups.len(), count);
+            assert!(groups.windows(2).all(|w| w[0] < w[1]));
+            assert!(groups.iter().all(|&g| g < NUMBER_OF_CUSTODY_GROUPS as u64));
+        }
+    }
+
+    #[test]
+    fn custody_groups_grow_with_count() {
+        let node_id = [7u8; 32];
+        let smaller = get_custody_groups(&node_id, CUSTODY_REQUIREMENT).unwrap();
+        let larger = get_custody_groups(&node_id, 2 * CUSTODY_REQUIREMENT).unwrap();
+        assert!(smaller.iter().all(|g| larger.contains(g)));
+    }
+
+    #[test]
+    fn custody_groups_wrap_at_max_node_id() {
+        let groups = get_custody_groups(&[0xffu8; 32], NUMBER_OF_CUSTODY_GROUPS - 1).unwrap();
+        assert_eq!(groups.len(), NUMBER_OF_CUSTODY_GROUPS - 1);
+    }
+
+    #[test]
+    fn custody_groups_known_values() {
+        // The min and max cases of the Fulu get_custody_groups vectors are fixed by the spec
+        // alone: no groups for a count of 0 and every group for NUMBER_OF_CUSTODY_GROUPS
+        for node_id in [[0u8; 32], [0xffu8; 32]] {
+            assert_eq!(get_custody_groups(&node_id, 0).unwrap(), Vec::<u64>::new());
+            assert_eq!(
+                get_custody_groups(&node_id, NUMBER_OF_CUSTODY_GROUPS).unwrap(),
+                (0..NUMBER_OF_CUSTODY_GROUPS as u64).collect::<Vec<_>>()
+            );
+        }
+
+        // Derived from the spec pseudocode with Python's hashlib: the group of each id is
+        // sha256(uint_to_bytes(uint256(id)))[0:8] as a little-endian u64 mod 128. From the max
+        // node id 2^256 - 1, the ids wrap around to 0 after the first group
+        assert_eq!(get_custody_groups(&[0u8; 32], 1).unwrap(), [102]);
+        assert_eq!(get_custody_groups(&[0u8; 32], 4).unwrap(), [1, 17, 87, 102]);
+        assert_eq!(get_custody_groups(&[0xffu8; 32], 1).unwrap(), [47]);
+        assert_eq!(get_custody_groups(&[0xffu8; 32], 2).unwrap(), [47, 102]);
+        assert_eq!(
+            get_custody_groups(&[0xffu8; 32], 4).unwrap(),
+            [1, 47, 87, 102]
+        );
+    }
+
+    #[test]
+    fn custody_groups_all_and_too_many() {
+        let all = get_custody_groups(&[1u8; 32], NUMBER_OF_CUSTODY_GROUPS).unwrap();
+        assert_eq!(
+            all,
+            (0..NUMBER_OF_CUSTODY_GROUPS as u64).collect::<Vec<_>>()
+        );
+        assert!(get_custody_groups(&[1u8; 32], NUMBER_OF_CUSTODY_GROUPS + 1).is_err());
+    }
+
+    #[test]
+    fn custody_columns_cover_groups() {
+        let node_id = [3u8; 32];
+        let groups = get_custody_groups(&node_id, CUSTODY_REQUIREMENT).unwrap();
+        let columns = get_custody_columns(&node_id, CUSTODY_REQUIREMENT).unwrap();
+        assert_eq!(
+            columns.len(),
+            CUSTODY_REQUIREMENT * (NUMBER_OF_COLUMNS / NUMBER_OF_CUSTODY_GROUPS)
+        );
+        assert!(columns
+            .iter()
+            .all(|c| groups.contains(&(c % NUMBER_OF_CUSTODY_GROUPS as u64))));
+        assert!(compute_columns_for_custody_group(NUMBER_OF_CUSTODY_GROUPS as u64).is_err());
+    }
+
+    #[test]
+    fn sample_columns_are_deterministic_per_slot() {
+        let seed = [9u8; 32];
+        let first = get_sample_columns(&seed, 100, SAMPLES_PER_SLOT).unwrap();
+        assert_eq!(first.len(), SAMPLES_PER_SLOT);
+        assert!(first.windows(2).all(|w| w[0] < w[1]));
+        assert!(first.iter().all(|&c| c < NUMBER_OF_COLUMNS as u64));
+        assert_eq!(
+            first,
+            get_sample_columns(&seed, 100, SAMPLES_PER_SLOT).unwrap()
+        );
+        assert_ne!(
+            first,
+            get_sample_columns(&seed, 101, SAMPLES_PER_SLOT).unwrap()
+        );
+
+        let all = get_sample_columns(&seed, 100, NUMBER_OF_COLUMNS).unwrap();
+        assert_eq!(all, (0..NUMBER_OF_COLUMNS as u64).collect::<Vec<_>>());
+        assert!(get_sample_columns(&seed, 100, NUMBER_OF_COLUMNS + 1).is_err());
+    }
+
+    #[test]
+    fn columns_for_custody_groups_known_values() {
+        // The min and max cases of the Fulu compute_columns_for_custody_group vectors. With one
+        // column per group, a group custodies the column with its own index
+        assert_eq!(compute_columns_for_custody_group(0).unwrap(), [0]);
+        assert_eq!(
+            compute_columns_for_custody_group(NUMBER_OF_CUSTODY_GROUPS as u64 - 1).unwrap(),
+            [NUMBER_OF_CUSTODY_GROUPS as u64 - 1]
+        );
+
+        assert_eq!(
+            columns_for_custody_groups(&[]).unwrap(),
+            Vec::<usize>::new()
+        );
+        // The groups of the max node id above, given unsorted and with a duplicate
+        assert_eq!(
+            columns_for_custody_groups(&[102, 47, 87, 1, 47]).unwrap(),
+            [1, 47, 87, 102]
+        );
+
+        let all: Vec<u64> = (0..NUMBER_OF_CUSTODY_GROUPS as u64).collect();
+        assert_eq!(
+            columns_for_custody_groups(&all).unwrap(),
+            (0..NUMBER_OF_COLUMNS).collect::<Vec<_>>()
+        );
+
+        assert!(columns_for_custody_groups(&[NUMBER_OF_CUSTODY_GROUPS as u64]).is_err());
+    }
+
+    #[test]
+    fn columns_for_custody_groups_matches_custody_columns() {
+        let node_id = [0x5au8; 32];
+        let groups = get_custody_groups(&node_id, CUSTODY_REQUIREMENT).unwrap();
+        let columns = columns_for_custody_groups(&groups).unwrap();
+        let expected = get_custody_columns(&node_id, CUSTODY_REQUIREMENT).unwrap();
+        assert_eq!(
+            columns,
+            expected.iter().map(|&c| c as usize).collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,57 @@
+#[cfg(test)]
+pub mod tests {
+    use kzg::digest::{DefaultDigest, Digest, Sha2Digest};
+    use kzg::eip_4844::hash;
+
+    // Test vectors from FIPS 180-2, appendix B
+    const VECTORS: [(&[u8], &str); 3] = [
+        (
+            b"",
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        ),
+        (
+            b"abc",
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+        ),
+        (
+            b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1",
+        ),
+    ];
+
+    fn check_provider<D: Digest>() {
+        for (msg, expected) in VECTORS {
+            assert_eq!(hex::encode(D::digest(msg)), expected);
+
+            // Feeding the message in pieces must not change the result
+            let mut digest = D::default();
+            for chunk in msg.chunks(7) {
+                digest.update(chunk);
+            }
+            assert_eq!(hex::encode(digest.finalize()), expected);
+        }
+    }
+
+    #[test]
+    fn sha2_digest_matches_vectors() {
+        check_provider::<Sha2Digest>();
+    }
+
+    #[test]
+    fn default_digest_matches_vectors() {
+        check_provider::<DefaultDigest>();
+    }
+
+    #[cfg(feature = "ring")]
+    #[test]
+    fn ring_digest_matches_vectors() {
+        check_provider::<kzg::digest::RingDigest>();
+    }
+
+    #[test]
+    fn hash_uses_default_digest() {
+        let msg = [0x5au8; 1000];
+        assert_eq!(hash(&msg), Sha2Digest::digest(&msg));
+        assert_eq!(hash(&msg), DefaultDigest::digest(&msg));
+    }
+}
@@ -0,0 +1,50 @@
+#[cfg(test)]
+pub mod tests {
+    use kzg::eip_4844::{BYTES_PER_BLOB, BYTES_PER_FIELD_ELEMENT};
+    use kzg::encoding::{decode_data, encode_data, MAX_DATA_BYTES_PER_BLOB};
+
+    #[test]
+    fn round_trip() {
+        for len in [0, 1, 27, 31, 32, 1000, MAX_DATA_BYTES_PER_BLOB] {
+            let data: Vec<u8> = (0..len).map(|i| (i * 7 + 3) as u8).collect();
+            let blob = encode_data(&data).unwrap();
+            assert_eq!(blob.len(), BYTES_PER_BLOB);
+            assert!(blob.chunks(BYTES_PER_FIELD_ELEMENT).all(|e| e[0] == 0));
+            assert_eq!(decode_data(&blob).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn layout() {
+        let blob = encode_data(&[0xaa; 30]).unwrap();
+        assert_eq!(blob[..5], [0, 0, 0, 0, 30]);
+        assert_eq!(blob[5..32], [0xaa; 27]);
+        assert_eq!(blob[32], 0);
+        assert_eq!(blob[33..36], [0xaa; 3]);
+        assert!(blob[36..].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn too_much_data() {
+        assert!(encode_data(&vec![1u8; MAX_DATA_BYTES_PER_BLOB + 1]).is_err());
+    }
+
+    #[test]
+    fn rejects_malformed_blobs() {
+        let blob = encode_data(b"rollup batch").unwrap();
+
+        assert!(decode_data(&blob[1..]).is_err());
+
+        let mut high_byte = blob.clone();
+        high_byte[BYTES_PER_FIELD_ELEMENT] = 1;
+        assert!(decode_data(&high_byte).is_err());
+
+        let mut dirty_padding = blob.clone();
+        dirty_padding[BYTES_PER_BLOB - 1] = 1;
+        assert!(decode_data(&dirty_padding).is_err());
+
+        let mut bad_length = blob;
+        bad_length[1..5].copy_from_slice(&u32::MAX.to_be_bytes());
+        assert!(decode_data(&bad_length).is_err());
+    }
+}
@@ -0,0 +1,18 @@
+#[cfg(test)]
+pub mod tests {
+    use kzg::digest::DefaultDigest;
+    use kzg::transcript::Transcript;
+
+    #[test]
+    fn transcript_layout() {
+        let mut transcript = Transcript::<DefaultDigest>::new(b"TRANSCRIPTTEST__");
+        transcript.append_u64(0x0102);
+        transcript.append_bytes(&[0xaa, 0xbb]);
+
+        let bytes = transcript.as_bytes();
+        assert_eq!(bytes.len(), 16 + 8 + 2);
+        assert_eq!(&bytes[..16], b"TRANSCRIPTTEST__");
+        assert_eq!(bytes[16..24], [0, 0, 0, 0, 0, 0, 1, 2]);
+        assert_eq!(bytes[24..], [0xaa, 0xbb]);
+    }
+}
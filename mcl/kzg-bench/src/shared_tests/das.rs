@@ -17,4 +17,16 @@ mod das_tests {
         assert!(init(CurveType::BLS12_381));
         das_extension_test_random::<Fr, FFTSettings>();
     }
+
+    #[test]
+    fn das_extension_with_explicit_root_() {
+        assert!(init(CurveType::BLS12_381));
+        das_extension_with_explicit_root::<Fr, FFTSettings>();
+    }
+
+    #[test]
+    fn fft_settings_new_with_root_wrong_order_() {
+        assert!(init(CurveType::BLS12_381));
+        fft_settings_new_with_root_wrong_order::<Fr, FFTSettings>();
+    }
 }
@@ -39,4 +39,12 @@ mod kzg_proofs_tests {
             &KZGSettings::generate_trusted_setup,
         );
     }
+
+    #[test]
+    fn with_shared_points_settings_() {
+        assert!(init(CurveType::BLS12_381));
+        with_shared_points_settings::<Fr, G1, G2, Polynomial, FFTSettings, KZGSettings>(
+            &KZGSettings::generate_trusted_setup,
+        );
+    }
 }
@@ -9,6 +9,7 @@ use kzg::eip_4844::{
 };
 use std::boxed::Box;
 use std::slice::{from_raw_parts, from_raw_parts_mut};
+use std::sync::Arc;
 
 use crate::data_types::g1::G1;
 use kzg::G1 as CommonG1;
@@ -25,9 +26,10 @@ unsafe fn cg1_to_g1(t: *const blst_p1) -> G1 {
 }
 
 unsafe fn ks_to_cks(t: &mut mKZGSettings, out: *mut CKZGSettings) {
-    assert_eq!(t.curve.g1_points.len(), t.fft_settings.max_width);
-    (*out).g1_values = t.curve.g1_points.as_mut_ptr() as _;
-    (*out).g2_values = t.curve.g2_points.as_mut_ptr() as _;
+    let curve = Arc::get_mut(&mut t.curve).expect("Setup points must not be shared");
+    assert_eq!(curve.g1_points.len(), t.fft_settings.max_width);
+    (*out).g1_values = curve.g1_points.as_mut_ptr() as _;
+    (*out).g2_values = curve.g2_points.as_mut_ptr() as _;
     let fs = CFFTSettings {
         max_width: t.fft_settings.max_width as _,
         roots_of_unity: t.fft_settings.expanded_roots_of_unity.as_mut_ptr() as _,
@@ -43,12 +45,12 @@ unsafe fn cks_to_ks(t: *const CKZGSettings) -> mKZGSettings {
     let fs = (*t).fs;
     let mw = (*fs).max_width as usize;
     let mut ks = mKZGSettings {
-        curve: crate::kzg10::Curve {
+        curve: Arc::new(crate::kzg10::Curve {
             g1_gen: G1::gen(),
             g2_gen: G2::gen(),
             g1_points: Vec::from_raw_parts((*t).g1_values as _, mw, mw),
             g2_points: Vec::from_raw_parts((*t).g2_values as _, 65, 65),
-        },
+        }),
         fft_settings: mFFTSettings {
             max_width: mw,
             root_of_unity: Fr::default(),
@@ -69,6 +71,18 @@ unsafe fn cks_to_ks(t: *const CKZGSettings) -> mKZGSettings {
     ks
 }
 
+// Leaves the setup storage to the C settings, but frees the Arc that wraps the points
+fn forget_ks(ks: mKZGSettings) {
+    let mKZGSettings {
+        fft_settings,
+        curve,
+    } = ks;
+    if let Ok(curve) = Arc::try_unwrap(curve) {
+        std::mem::forget(curve);
+    }
+    std::mem::forget(fft_settings);
+}
+
 unsafe fn deserialize_blob(blob: *const Blob) -> Result<Vec<Fr>, C_KZG_RET> {
     (*blob)
         .bytes
@@ -117,7 +131,7 @@ pub unsafe extern "C" fn load_trusted_setup(
         from_raw_parts(g2_bytes, n2 * BYTES_PER_G2),
     );
     ks_to_cks(&mut mks, out);
-    std::mem::forget(mks);
+    forget_ks(mks);
     C_KZG_RET_OK
 }
 
@@ -137,7 +151,7 @@ pub unsafe extern "C" fn load_trusted_setup_file(
     let mut mks =
         crate::eip_4844::load_trusted_setup_from_bytes(g1_bytes.as_slice(), g2_bytes.as_slice());
     ks_to_cks(&mut mks, out);
-    std::mem::forget(mks);
+    forget_ks(mks);
 
     C_KZG_RET_OK
 }
@@ -176,7 +190,7 @@ pub unsafe extern "C" fn compute_blob_kzg_proof(
         &commitment_g1.unwrap(),
         &ms,
     );
-    std::mem::forget(ms);
+    forget_ks(ms);
 
     if let Ok(proof) = proof {
         (*out).bytes = proof.to_bytes();
@@ -214,7 +228,7 @@ pub unsafe extern "C" fn verify_blob_kzg_proof(
         &proof_g1.unwrap(),
         &ms,
     );
-    std::mem::forget(ms);
+    forget_ks(ms);
 
     if let Ok(result) = result {
         *ok = result;
@@ -262,7 +276,7 @@ pub unsafe extern "C" fn verify_blob_kzg_proof_batch(
             &proofs,
             &ms,
         );
-        std::mem::forget(ms);
+        forget_ks(ms);
 
         if let Ok(result) = result {
             *ok = result;
@@ -292,7 +306,7 @@ pub unsafe extern "C" fn blob_to_kzg_commitment(
     let ms = cks_to_ks(s);
     let tmp = crate::eip_4844::blob_to_kzg_commitment(&deserialized_blob.unwrap(), &ms);
     (*out).bytes = G1::to_bytes(&tmp);
-    std::mem::forget(ms);
+    forget_ks(ms);
 
     C_KZG_RET_OK
 }
@@ -326,7 +340,7 @@ pub unsafe extern "C" fn verify_kzg_proof(
         &g1proof.unwrap(),
         &ms,
     );
-    std::mem::forget(ms);
+    forget_ks(ms);
 
     if let Ok(result) = result {
         *ok = result;
@@ -360,7 +374,7 @@ pub unsafe extern "C" fn compute_kzg_proof(
         crate::eip_4844::compute_kzg_proof(&deserialized_blob.unwrap(), &frz.unwrap(), &ms);
     (*proof_out).bytes = G1::to_bytes(&proof_out_tmp);
     (*y_out).bytes = Fr::to_bytes(&fry_tmp);
-    std::mem::forget(ms);
+    forget_ks(ms);
 
     C_KZG_RET_OK
 }
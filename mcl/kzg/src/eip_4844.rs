@@ -14,6 +14,7 @@ use kzg::{cfg_into_iter, G1 as _, G2 as _};
 use std::convert::TryInto;
 use std::fs::File;
 use std::io::Read;
+use std::sync::Arc;
 use std::usize;
 
 #[cfg(feature = "parallel")]
@@ -86,12 +87,12 @@ pub fn load_trusted_setup_from_bytes(g1_bytes: &[u8], g2_bytes: &[u8]) -> KZGSet
 
     KZGSettings {
         fft_settings: fs,
-        curve: Curve {
+        curve: Arc::new(Curve {
             g1_gen: G1::gen(),
             g2_gen: G2::gen(),
             g1_points: g1_values,
             g2_points: g2_values,
-        },
+        }),
     }
 }
 
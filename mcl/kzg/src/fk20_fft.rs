@@ -1,4 +1,5 @@
 use kzg::common_utils::{is_power_of_2, next_pow_of_2, reverse_bit_order};
+use kzg::FFTSettings as CommonFFTSettings;
 
 use crate::data_types::{fp::*, fr::*, g1::*};
 use std::iter;
@@ -293,21 +294,7 @@ impl FFTSettings {
             root_of_unity = SCALE_2_ROOT_OF_UNITY[max_scale as usize]
         }
 
-        let expanded_roots_of_unity = expand_root_of_unity(&root_of_unity);
-        let mut reverse_roots_of_unity = expanded_roots_of_unity.clone();
-        reverse_roots_of_unity.reverse();
-
-        // Permute the roots of unity
-        let mut roots_of_unity = expanded_roots_of_unity.clone();
-        reverse_bit_order(&mut roots_of_unity)?;
-
-        Ok(FFTSettings {
-            max_width: 1 << max_scale,
-            root_of_unity,
-            expanded_roots_of_unity,
-            reverse_roots_of_unity,
-            roots_of_unity,
-        })
+        <FFTSettings as CommonFFTSettings<Fr>>::new_with_root(max_scale as usize, &root_of_unity)
     }
 
     // #[cfg(feature = "parallel")]
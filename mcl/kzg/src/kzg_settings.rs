@@ -3,18 +3,19 @@ use crate::fk20_fft::FFTSettings;
 use crate::kzg10::Curve;
 use crate::kzg10::Polynomial;
 use kzg::common_utils::is_power_of_2;
+use std::sync::Arc;
 
 #[derive(Debug, Clone, Default)]
 pub struct KZGSettings {
     pub fft_settings: FFTSettings,
-    pub curve: Curve,
+    pub curve: Arc<Curve>,
 }
 
 impl KZGSettings {
     pub fn new_from_curve(curve: &Curve, fft_settings: &FFTSettings) -> Self {
         KZGSettings {
             fft_settings: fft_settings.clone(),
-            curve: curve.clone(),
+            curve: Arc::new(curve.clone()),
         }
     }
 
@@ -50,7 +51,7 @@ impl KZGSettings {
 
         Ok(KZGSettings {
             fft_settings: fft_settings.clone(),
-            curve,
+            curve: Arc::new(curve),
         })
     }
 
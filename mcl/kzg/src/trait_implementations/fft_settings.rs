@@ -1,5 +1,6 @@
 use crate::data_types::fr::Fr;
-use crate::fk20_fft::{FFTSettings, SCALE_2_ROOT_OF_UNITY_PR7_STRINGS};
+use crate::fk20_fft::{expand_root_of_unity, FFTSettings, SCALE_2_ROOT_OF_UNITY_PR7_STRINGS};
+use kzg::common_utils::reverse_bit_order;
 use kzg::FFTSettings as CommonFFTSettings;
 
 impl CommonFFTSettings<Fr> for FFTSettings {
@@ -8,6 +9,46 @@ impl CommonFFTSettings<Fr> for FFTSettings {
         FFTSettings::new_custom_primitive_roots(scale as u8, SCALE_2_ROOT_OF_UNITY_PR7_STRINGS)
     }
 
+    fn new_with_root(scale: usize, root_of_unity: &Fr) -> Result<FFTSettings, String> {
+        if scale >= 32 {
+            return Err(String::from(
+                "Scale is expected to be within root of unity matrix row size",
+            ));
+        }
+
+        // The root must have order exactly 2^scale, otherwise expansion would not stop at the
+        // right width
+        let half_order_power = (1..scale).fold(*root_of_unity, |acc, _| &acc * &acc);
+        let has_order = if scale == 0 {
+            root_of_unity.is_one()
+        } else {
+            !half_order_power.is_one() && (&half_order_power * &half_order_power).is_one()
+        };
+        if !has_order {
+            return Err(String::from("Root of unity has invalid scale"));
+        }
+
+        // max_width = 2 ^ max_scale
+        let max_width: usize = 1 << scale;
+
+        // create max_width of roots & store them reversed as well
+        let expanded_roots_of_unity = expand_root_of_unity(root_of_unity);
+        let mut reverse_roots_of_unity = expanded_roots_of_unity.clone();
+        reverse_roots_of_unity.reverse();
+
+        // Permute the roots of unity, without the repeated 1 that ends the expansion
+        let mut roots_of_unity = expanded_roots_of_unity[..max_width].to_vec();
+        reverse_bit_order(&mut roots_of_unity)?;
+
+        Ok(FFTSettings {
+            max_width,
+            root_of_unity: *root_of_unity,
+            expanded_roots_of_unity,
+            reverse_roots_of_unity,
+            roots_of_unity,
+        })
+    }
+
     fn get_max_width(&self) -> usize {
         self.max_width
     }
@@ -29,6 +70,9 @@ impl CommonFFTSettings<Fr> for FFTSettings {
     }
 
     fn get_roots_of_unity_at(&self, i: usize) -> Fr {
+        if i == self.max_width {
+            return self.roots_of_unity[0];
+        }
         self.roots_of_unity[i]
     }
 
@@ -14,6 +14,14 @@ impl CommonKZGSettings<Fr, G1, G2, FFTSettings, Polynomial> for KZGSettings {
         KZGSettings::new(secret_g1, secret_g2, length, fs)
     }
 
+    fn with_shared_points(base: &Self, _with_precomputation: bool) -> Result<Self, String> {
+        // There is no MSM precomputation to build, so only the points are shared
+        Ok(KZGSettings {
+            fft_settings: base.fft_settings.clone(),
+            curve: base.curve.clone(),
+        })
+    }
+
     fn commit_to_poly(&self, polynomial: &Polynomial) -> Result<G1, String> {
         polynomial.commit(&self.curve.g1_points)
     }
@@ -48,4 +56,16 @@ impl CommonKZGSettings<Fr, G1, G2, FFTSettings, Polynomial> for KZGSettings {
     fn get_roots_of_unity_at(&self, i: usize) -> Fr {
         self.fft_settings.roots_of_unity[i]
     }
+
+    fn get_fft_settings(&self) -> &FFTSettings {
+        &self.fft_settings
+    }
+
+    fn get_g1_secret(&self) -> &[G1] {
+        &self.curve.g1_points
+    }
+
+    fn get_g2_secret(&self) -> &[G2] {
+        &self.curve.g2_points
+    }
 }
@@ -57,6 +57,10 @@ impl Poly<Fr> for Polynomial {
     fn mul_direct(&mut self, x: &Self, len: usize) -> Result<Self, String> {
         Polynomial::mul_direct(self, x, len)
     }
+
+    fn mul(&mut self, x: &Self, len: usize) -> Result<Self, String> {
+        Polynomial::mul(self, x, len)
+    }
 }
 
 impl FFTSettingsPoly<Fr, Polynomial, FFTSettings> for FFTSettings {
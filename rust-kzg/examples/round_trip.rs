@@ -0,0 +1,62 @@
+//! Commits to a blob, proves and verifies it, and extends data for sampling, without naming the
+//! backend. Builds with any of the backend features:
+//!
+//! ```sh
+//! cargo run -p rust-kzg --example round_trip
+//! cargo run -p rust-kzg --example round_trip --no-default-features --features backend-arkworks
+//! ```
+
+use rust_kzg::prelude::*;
+use rust_kzg::{
+    blob_to_kzg_commitment, compute_blob_kzg_proof, compute_kzg_proof, das_fft_extension,
+    load_trusted_setup_file, verify_blob_kzg_proof, verify_blob_kzg_proof_batch, verify_kzg_proof,
+    Backend, FftSettings, Fr, FIELD_ELEMENTS_PER_BLOB,
+};
+
+pub const TRUSTED_SETUP_FILE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../kzg-bench/src/trusted_setup.txt"
+);
+
+pub fn run(trusted_setup_file: &str) -> Result<(), String> {
+    let settings = load_trusted_setup_file(trusted_setup_file)?;
+
+    let blob: Vec<Fr> = (0..FIELD_ELEMENTS_PER_BLOB as u64)
+        .map(|i| Fr::from_u64(i * i + 1))
+        .collect();
+
+    let commitment = blob_to_kzg_commitment(&blob, &settings)?;
+
+    let z = Fr::from_u64(0x4844);
+    let (proof, y) = compute_kzg_proof(&blob, &z, &settings)?;
+    if !verify_kzg_proof(&commitment, &z, &y, &proof, &settings)? {
+        return Err("KZG proof did not verify".to_string());
+    }
+
+    let blob_proof = compute_blob_kzg_proof(&blob, &commitment, &settings)?;
+    if !verify_blob_kzg_proof(&blob, &commitment, &blob_proof, &settings)? {
+        return Err("Blob KZG proof did not verify".to_string());
+    }
+    if !verify_blob_kzg_proof_batch(&[blob], &[commitment], &[blob_proof], &settings)? {
+        return Err("Blob KZG proof batch did not verify".to_string());
+    }
+
+    let fft_settings = FftSettings::new(4)?;
+    let evens: Vec<Fr> = (0..8).map(Fr::from_u64).collect();
+    let odds = das_fft_extension(&fft_settings, &evens)?;
+    if odds.len() != evens.len() {
+        return Err("Unexpected extension length".to_string());
+    }
+
+    println!(
+        "{}: commitment {}",
+        Backend::NAME,
+        hex::encode(commitment.to_bytes())
+    );
+    Ok(())
+}
+
+#[allow(dead_code)]
+fn main() {
+    run(TRUSTED_SETUP_FILE).unwrap();
+}
@@ -0,0 +1,216 @@
+//! Backend independent entry point to rust-kzg.
+//!
+//! Exactly one backend is selected with a feature: `backend-blst` (default), `backend-arkworks`
+//! or `backend-constantine`. Its concrete types are re-exported under the names below, so code
+//! written against this crate builds unchanged with any backend. To select a backend other than
+//! blst, disable default features:
+//!
+//! ```toml
+//! rust-kzg = { version = "0.1.0", default-features = false, features = ["backend-arkworks"] }
+//! ```
+
+#[cfg(not(any(
+    feature = "backend-blst",
+    feature = "backend-arkworks",
+    feature = "backend-constantine"
+)))]
+compile_error!(
+    "no backend selected, enable one of `backend-blst`, `backend-arkworks` or `backend-constantine`"
+);
+
+#[cfg(any(
+    all(feature = "backend-blst", feature = "backend-arkworks"),
+    all(feature = "backend-blst", feature = "backend-constantine"),
+    all(feature = "backend-arkworks", feature = "backend-constantine"),
+))]
+compile_error!(
+    "backend features are mutually exclusive, enable only one of `backend-blst`, `backend-arkworks` or `backend-constantine`"
+);
+
+#[cfg(feature = "backend-blst")]
+mod selected {
+    pub use rust_kzg_blst::eip_4844::load_trusted_setup_filename_rust;
+    pub use rust_kzg_blst::types::{
+        fft_settings::FsFFTSettings as FftSettings,
+        fp::FsFp as Fp,
+        fr::FsFr as Fr,
+        g1::{FsG1 as G1, FsG1Affine as G1Affine},
+        g2::FsG2 as G2,
+        kzg_settings::FsKZGSettings as KzgSettings,
+        poly::FsPoly as Poly,
+    };
+
+    pub const NAME: &str = "blst";
+}
+
+#[cfg(all(feature = "backend-arkworks", not(feature = "backend-blst")))]
+mod selected {
+    pub use rust_kzg_arkworks::eip_4844::load_trusted_setup_filename_rust;
+    pub use rust_kzg_arkworks::kzg_proofs::{
+        FFTSettings as FftSettings, KZGSettings as KzgSettings,
+    };
+    pub use rust_kzg_arkworks::kzg_types::{
+        ArkFp as Fp, ArkFr as Fr, ArkG1 as G1, ArkG1Affine as G1Affine, ArkG2 as G2,
+    };
+    pub use rust_kzg_arkworks::utils::PolyData as Poly;
+
+    pub const NAME: &str = "arkworks";
+}
+
+#[cfg(all(
+    feature = "backend-constantine",
+    not(any(feature = "backend-blst", feature = "backend-arkworks"))
+))]
+mod selected {
+    pub use rust_kzg_constantine::eip_4844::load_trusted_setup_filename_rust;
+    pub use rust_kzg_constantine::types::{
+        fft_settings::CtFFTSettings as FftSettings,
+        fp::CtFp as Fp,
+        fr::CtFr as Fr,
+        g1::{CtG1 as G1, CtG1Affine as G1Affine},
+        g2::CtG2 as G2,
+        kzg_settings::CtKZGSettings as KzgSettings,
+        poly::CtPoly as Poly,
+    };
+
+    pub const NAME: &str = "constantine";
+}
+
+pub use kzg;
+pub use kzg::eip_4844::{
+    BYTES_PER_BLOB, BYTES_PER_COMMITMENT, BYTES_PER_FIELD_ELEMENT, BYTES_PER_PROOF,
+    FIELD_ELEMENTS_PER_BLOB,
+};
+pub use selected::{FftSettings, Fp, Fr, G1Affine, KzgSettings, Poly, G1, G2};
+
+/// Traits needed to call methods on the re-exported types, imported anonymously so they do not
+/// clash with the type names.
+pub mod prelude {
+    pub use kzg::{
+        FFTFr as _, FFTSettings as _, Fr as _, G1Mul as _, KZGSettings as _, Poly as _,
+        PolyRecover as _, DAS as _, G1 as _, G2 as _,
+    };
+}
+
+/// The backend selected at compile time.
+pub struct Backend;
+
+impl Backend {
+    /// Short name of the backend, e.g. `"blst"`.
+    pub const NAME: &'static str = selected::NAME;
+}
+
+pub fn load_trusted_setup_file(filepath: &str) -> Result<KzgSettings, String> {
+    selected::load_trusted_setup_filename_rust(filepath)
+}
+
+pub fn bytes_to_blob(bytes: &[u8]) -> Result<Vec<Fr>, String> {
+    kzg::eip_4844::bytes_to_blob(bytes)
+}
+
+pub fn blob_to_kzg_commitment(blob: &[Fr], settings: &KzgSettings) -> Result<G1, String> {
+    kzg::eip_4844::blob_to_kzg_commitment_rust::<
+        Fr,
+        G1,
+        G2,
+        FftSettings,
+        Poly,
+        KzgSettings,
+        Fp,
+        G1Affine,
+    >(blob, settings)
+}
+
+pub fn blob_to_kzg_commitment_batch(
+    blobs: &[Vec<Fr>],
+    settings: &KzgSettings,
+) -> Result<Vec<G1>, String> {
+    kzg::eip_4844::blob_to_kzg_commitment_batch_rust::<
+        Fr,
+        G1,
+        G2,
+        FftSettings,
+        Poly,
+        KzgSettings,
+        Fp,
+        G1Affine,
+        Vec<Fr>,
+    >(blobs, settings)
+}
+
+pub fn compute_kzg_proof(blob: &[Fr], z: &Fr, settings: &KzgSettings) -> Result<(G1, Fr), String> {
+    kzg::eip_4844::compute_kzg_proof_rust::<Fr, G1, G2, FftSettings, Poly, KzgSettings, Fp, G1Affine>(
+        blob, z, settings,
+    )
+}
+
+pub fn compute_blob_kzg_proof(
+    blob: &[Fr],
+    commitment: &G1,
+    settings: &KzgSettings,
+) -> Result<G1, String> {
+    kzg::eip_4844::compute_blob_kzg_proof_rust::<
+        Fr,
+        G1,
+        G2,
+        FftSettings,
+        Poly,
+        KzgSettings,
+        Fp,
+        G1Affine,
+    >(blob, commitment, settings)
+}
+
+pub fn verify_kzg_proof(
+    commitment: &G1,
+    z: &Fr,
+    y: &Fr,
+    proof: &G1,
+    settings: &KzgSettings,
+) -> Result<bool, String> {
+    kzg::eip_4844::verify_kzg_proof_rust::<Fr, G1, G2, FftSettings, Poly, KzgSettings, Fp, G1Affine>(
+        commitment, z, y, proof, settings,
+    )
+}
+
+pub fn verify_blob_kzg_proof(
+    blob: &[Fr],
+    commitment: &G1,
+    proof: &G1,
+    settings: &KzgSettings,
+) -> Result<bool, String> {
+    kzg::eip_4844::verify_blob_kzg_proof_rust::<
+        Fr,
+        G1,
+        G2,
+        FftSettings,
+        Poly,
+        KzgSettings,
+        Fp,
+        G1Affine,
+    >(blob, commitment, proof, settings)
+}
+
+pub fn verify_blob_kzg_proof_batch(
+    blobs: &[Vec<Fr>],
+    commitments: &[G1],
+    proofs: &[G1],
+    settings: &KzgSettings,
+) -> Result<bool, String> {
+    kzg::eip_4844::verify_blob_kzg_proof_batch_rust::<
+        Fr,
+        G1,
+        G2,
+        FftSettings,
+        Poly,
+        KzgSettings,
+        Fp,
+        G1Affine,
+        Vec<Fr>,
+    >(blobs, commitments, proofs, settings)
+}
+
+/// Computes the odd-indexed values that extend `evens`, see [`kzg::DAS::das_fft_extension`].
+pub fn das_fft_extension(settings: &FftSettings, evens: &[Fr]) -> Result<Vec<Fr>, String> {
+    kzg::DAS::das_fft_extension(settings, evens)
+}
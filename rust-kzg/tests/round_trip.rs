@@ -0,0 +1,7 @@
+#[path = "../examples/round_trip.rs"]
+mod round_trip;
+
+#[test]
+fn round_trip_with_selected_backend() {
+    round_trip::run(round_trip::TRUSTED_SETUP_FILE).unwrap();
+}
@@ -14,6 +14,7 @@ use kzg::eip_4844::{
 };
 use kzg::{cfg_into_iter, Fr, G1};
 use std::ptr::null_mut;
+use std::sync::Arc;
 
 #[cfg(feature = "std")]
 use libc::FILE;
@@ -71,13 +72,13 @@ fn kzg_settings_to_rust(c_settings: &CKZGSettings) -> Result<KZGSettings, String
         core::slice::from_raw_parts(c_settings.g1_values, TRUSTED_SETUP_NUM_G1_POINTS)
             .iter()
             .map(|r| ZG1::from_blst_p1(*r))
-            .collect::<Vec<ZG1>>()
+            .collect::<Arc<[ZG1]>>()
     };
     let secret_g2 = unsafe {
         core::slice::from_raw_parts(c_settings.g2_values, TRUSTED_SETUP_NUM_G2_POINTS)
             .iter()
             .map(|r| ZG2::from_blst_p2(*r))
-            .collect::<Vec<ZG2>>()
+            .collect::<Arc<[ZG2]>>()
     };
     Ok(KZGSettings {
         fs: fft_settings_to_rust(c_settings)?,
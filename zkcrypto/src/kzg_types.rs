@@ -6,6 +6,7 @@ use crate::fft_g1::g1_linear_combination;
 use crate::kzg_proofs::{
     expand_root_of_unity, pairings_verify, FFTSettings as ZFFTSettings, KZGSettings as ZKZGSettings,
 };
+use crate::multiscalar_mul::msm_variable_base;
 use crate::poly::PolyData;
 use crate::utils::{
     blst_fr_into_pc_fr, blst_p1_into_pc_g1projective, blst_p2_into_pc_g2projective,
@@ -14,7 +15,7 @@ use crate::utils::{
 use bls12_381::{Fp, G1Affine, G1Projective, G2Affine, G2Projective, Scalar, MODULUS, R2};
 use blst::{blst_fr, blst_p1};
 use ff::Field;
-use kzg::common_utils::reverse_bit_order;
+use kzg::common_utils::{check_degree_bound, reverse_bit_order};
 use kzg::eip_4844::{BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1, BYTES_PER_G2};
 use kzg::msm::precompute::{precompute, PrecomputationTable};
 use kzg::G1Affine as G1AffineTrait;
@@ -23,6 +24,7 @@ use kzg::{
     KZGSettings, PairingVerify, Poly, Scalar256, G1, G2,
 };
 use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+use std::sync::Arc;
 
 use ff::derive::sbb;
 use subtle::{Choice, ConstantTimeEq, CtOption};
@@ -554,6 +556,34 @@ impl G1AffineTrait<ZG1, ZFp> for ZG1Affine {
         }
     }
 
+    fn batch_scalar_mul<TFr: KzgFr>(
+        points: &[Self],
+        scalars: &[TFr],
+        n: usize,
+    ) -> Result<ZG1, String>
+    where
+        ZG1: G1GetFp<ZFp> + G1Mul<TFr>,
+    {
+        if n > points.len() || n > scalars.len() {
+            return Err(format!(
+                "Expected at least {} points and scalars, got {} and {}",
+                n,
+                points.len(),
+                scalars.len()
+            ));
+        }
+
+        // The zkcrypto pippenger works on projective points, so there is no affine fast path here
+        let points = points[0..n].iter().map(|p| p.to_proj()).collect::<Vec<_>>();
+        let scalars = scalars[0..n]
+            .iter()
+            .map(|s| ZFr::from_u64_arr(&s.to_u64_arr()))
+            .collect::<Vec<_>>();
+        Ok(ZG1 {
+            proj: msm_variable_base(&points, &scalars),
+        })
+    }
+
     fn x(&self) -> &ZFp {
         unsafe { core::mem::transmute(&self.0.x) }
     }
@@ -772,12 +802,21 @@ impl FFTSettings<ZFr> for ZFFTSettings {
             ));
         }
 
+        Self::new_with_root(scale, &ZFr::from_u64_arr(&SCALE2_ROOT_OF_UNITY[scale]))
+    }
+
+    fn new_with_root(scale: usize, root_of_unity: &ZFr) -> Result<Self, String> {
+        if scale >= SCALE2_ROOT_OF_UNITY.len() {
+            return Err(String::from(
+                "Scale is expected to be within root of unity matrix row size",
+            ));
+        }
+
         // max_width = 2 ^ max_scale
         let max_width: usize = 1 << scale;
-        let root_of_unity = ZFr::from_u64_arr(&SCALE2_ROOT_OF_UNITY[scale]);
 
         // create max_width of roots & store them reversed as well
-        let expanded_roots_of_unity = expand_root_of_unity(&root_of_unity, max_width).unwrap();
+        let expanded_roots_of_unity = expand_root_of_unity(root_of_unity, max_width)?;
         let mut reverse_roots_of_unity = expanded_roots_of_unity.clone();
         reverse_roots_of_unity.reverse();
 
@@ -788,7 +827,7 @@ impl FFTSettings<ZFr> for ZFFTSettings {
 
         Ok(Self {
             max_width,
-            root_of_unity,
+            root_of_unity: *root_of_unity,
             expanded_roots_of_unity,
             reverse_roots_of_unity,
             roots_of_unity,
@@ -816,6 +855,9 @@ impl FFTSettings<ZFr> for ZFFTSettings {
     }
 
     fn get_roots_of_unity_at(&self, i: usize) -> ZFr {
+        if i == self.max_width {
+            return self.roots_of_unity[0];
+        }
         self.roots_of_unity[i]
     }
 
@@ -832,17 +874,30 @@ impl KZGSettings<ZFr, ZG1, ZG2, ZFFTSettings, PolyData, ZFp, ZG1Affine> for ZKZG
         fft_settings: &ZFFTSettings,
     ) -> Result<ZKZGSettings, String> {
         Ok(Self {
-            secret_g1: secret_g1.to_vec(),
-            secret_g2: secret_g2.to_vec(),
+            secret_g1: secret_g1.into(),
+            secret_g2: secret_g2.into(),
             fs: fft_settings.clone(),
-            precomputation: precompute(secret_g1).ok().flatten(),
+            precomputation: precompute(secret_g1).ok().flatten().map(Arc::new),
+        })
+    }
+
+    fn with_shared_points(base: &Self, with_precomputation: bool) -> Result<Self, String> {
+        let precomputation = match (&base.precomputation, with_precomputation) {
+            (_, false) => None,
+            (Some(table), true) => Some(table.clone()),
+            (None, true) => precompute(&base.secret_g1).ok().flatten().map(Arc::new),
+        };
+
+        Ok(Self {
+            fs: base.fs.clone(),
+            secret_g1: base.secret_g1.clone(),
+            secret_g2: base.secret_g2.clone(),
+            precomputation,
         })
     }
 
     fn commit_to_poly(&self, p: &PolyData) -> Result<ZG1, String> {
-        if p.coeffs.len() > self.secret_g1.len() {
-            return Err(String::from("Polynomial is longer than secret g1"));
-        }
+        check_degree_bound(p.coeffs.len(), self.secret_g1.len())?;
 
         let mut out = ZG1::default();
         g1_linear_combination(&mut out, &self.secret_g1, &p.coeffs, p.coeffs.len(), None);
@@ -855,6 +910,8 @@ impl KZGSettings<ZFr, ZG1, ZG2, ZFFTSettings, PolyData, ZFp, ZG1Affine> for ZKZG
             return Err(String::from("Polynomial must not be empty"));
         }
 
+        check_degree_bound(p.coeffs.len(), self.secret_g1.len())?;
+
         // `-(x0^n)`, where `n` is `1`
         let divisor_0 = x.negate();
 
@@ -890,6 +947,8 @@ impl KZGSettings<ZFr, ZG1, ZG2, ZFFTSettings, PolyData, ZFp, ZG1Affine> for ZKZG
             return Err(String::from("Polynomial must not be empty"));
         }
 
+        check_degree_bound(p.coeffs.len(), self.secret_g1.len())?;
+
         if !n.is_power_of_two() {
             return Err(String::from("n must be a power of two"));
         }
@@ -984,6 +1043,6 @@ impl KZGSettings<ZFr, ZG1, ZG2, ZFFTSettings, PolyData, ZFp, ZG1Affine> for ZKZG
     }
 
     fn get_precomputation(&self) -> Option<&PrecomputationTable<ZFr, ZG1, ZFp, ZG1Affine>> {
-        self.precomputation.as_ref()
+        self.precomputation.as_ref().map(|v| v.as_ref())
     }
 }
@@ -273,6 +273,27 @@ impl Poly<ZFr> for PolyData {
 
         Ok(ret)
     }
+
+    fn mul(&mut self, multiplier: &Self, output_len: usize) -> Result<Self, String> {
+        if self.len() < 64 || multiplier.len() < 64 || output_len < 128 {
+            // Tunable parameter
+            self.mul_direct(multiplier, output_len)
+        } else {
+            self.mul_fft(multiplier, output_len)
+        }
+    }
+
+    fn pad(&self, out_length: usize) -> Self {
+        let mut ret = Self {
+            coeffs: vec![ZFr::zero(); out_length],
+        };
+
+        for i in 0..self.len().min(out_length) {
+            ret.coeffs[i] = self.coeffs[i];
+        }
+
+        ret
+    }
 }
 
 impl FFTSettingsPoly<ZFr, PolyData, ZFFTSettings> for ZFFTSettings {
@@ -312,18 +333,6 @@ impl PolyData {
         }
     }
 
-    pub fn pad(&self, out_length: usize) -> Self {
-        let mut ret = Self {
-            coeffs: vec![ZFr::zero(); out_length],
-        };
-
-        for i in 0..self.len().min(out_length) {
-            ret.coeffs[i] = self.coeffs[i];
-        }
-
-        ret
-    }
-
     pub fn flip(&self) -> Result<PolyData, String> {
         let mut ret = PolyData {
             coeffs: vec![ZFr::default(); self.len()],
@@ -392,13 +401,4 @@ impl PolyData {
 
         Ok(ret)
     }
-
-    pub fn mul(&mut self, multiplier: &Self, output_len: usize) -> Result<Self, String> {
-        if self.len() < 64 || multiplier.len() < 64 || output_len < 128 {
-            // Tunable parameter
-            self.mul_direct(multiplier, output_len)
-        } else {
-            self.mul_fft(multiplier, output_len)
-        }
-    }
 }
@@ -0,0 +1,29 @@
+#[cfg(test)]
+mod tests {
+    use kzg_bench::tests::consistency::{
+        das_outputs_match_reference_test, eip_4844_outputs_match_reference_test,
+    };
+    use rust_kzg_zkcrypto::eip_4844::load_trusted_setup_filename_rust;
+    use rust_kzg_zkcrypto::kzg_proofs::{FFTSettings, KZGSettings};
+    use rust_kzg_zkcrypto::kzg_types::{ZFp, ZFr, ZG1Affine, ZG1, ZG2};
+    use rust_kzg_zkcrypto::poly::PolyData;
+
+    #[test]
+    pub fn eip_4844_outputs_match_reference() {
+        eip_4844_outputs_match_reference_test::<
+            ZFr,
+            ZG1,
+            ZG2,
+            FFTSettings,
+            PolyData,
+            KZGSettings,
+            ZFp,
+            ZG1Affine,
+        >("zkcrypto", &load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn das_outputs_match_reference() {
+        das_outputs_match_reference_test::<ZFr, FFTSettings, PolyData>("zkcrypto");
+    }
+}
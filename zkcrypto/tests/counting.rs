@@ -0,0 +1,33 @@
+#[cfg(all(test, feature = "counting"))]
+mod tests {
+    use kzg_bench::tests::counting::{
+        batch_challenge_op_counts, blob_flow_op_counts, counting_delegates_and_counts,
+        field_helpers_op_counts,
+    };
+    use rust_kzg_zkcrypto::eip_4844::load_trusted_setup_filename_rust;
+    use rust_kzg_zkcrypto::kzg_proofs::{FFTSettings, KZGSettings};
+    use rust_kzg_zkcrypto::kzg_types::{ZFp, ZFr, ZG1Affine, ZG1, ZG2};
+    use rust_kzg_zkcrypto::poly::PolyData;
+
+    #[test]
+    fn counting_delegates_and_counts_() {
+        counting_delegates_and_counts::<ZFr, ZG1>();
+    }
+
+    #[test]
+    fn field_helpers_op_counts_() {
+        field_helpers_op_counts::<ZFr>();
+    }
+
+    #[test]
+    fn batch_challenge_op_counts_() {
+        batch_challenge_op_counts::<ZFr, ZG1>();
+    }
+
+    #[test]
+    fn blob_flow_op_counts_() {
+        blob_flow_op_counts::<ZFr, ZG1, ZG2, PolyData, FFTSettings, KZGSettings, ZFp, ZG1Affine>(
+            &load_trusted_setup_filename_rust,
+        );
+    }
+}
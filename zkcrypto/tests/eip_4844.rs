@@ -7,18 +7,32 @@ mod tests {
         verify_blob_kzg_proof_rust, verify_kzg_proof_rust,
     };
     use kzg::Fr;
+    #[cfg(feature = "commitment-cache")]
+    use kzg_bench::tests::eip_4844::commitment_cache_test;
+    #[cfg(feature = "ssz")]
+    use kzg_bench::tests::eip_4844::ssz_round_trip_test;
     use kzg_bench::tests::eip_4844::{
-        blob_to_kzg_commitment_test, bytes_to_bls_field_test,
+        aggregate_kzg_proof_test, blob_newtype_batch_apis_test, blob_newtype_test,
+        blob_to_kzg_commitment_batch_test, blob_to_kzg_commitment_streaming_test,
+        blob_to_kzg_commitment_test, build_blob_sidecars_test, bytes_to_bls_field_test,
         compute_and_verify_blob_kzg_proof_fails_with_incorrect_proof_test,
         compute_and_verify_blob_kzg_proof_test,
         compute_and_verify_kzg_proof_fails_with_incorrect_proof_test,
         compute_and_verify_kzg_proof_round_trip_test,
-        compute_and_verify_kzg_proof_within_domain_test, compute_kzg_proof_test,
-        compute_powers_test, test_vectors_blob_to_kzg_commitment,
-        test_vectors_compute_blob_kzg_proof, test_vectors_compute_kzg_proof,
-        test_vectors_verify_blob_kzg_proof, test_vectors_verify_blob_kzg_proof_batch,
-        test_vectors_verify_kzg_proof, verify_kzg_proof_batch_fails_with_incorrect_proof_test,
-        verify_kzg_proof_batch_test,
+        compute_and_verify_kzg_proof_within_domain_test,
+        compute_blob_kzg_proof_incorrect_blob_length_test, compute_blob_kzg_proof_streaming_test,
+        compute_kzg_proof_test, compute_powers_test, compute_r_powers_degenerate_challenge_test,
+        custom_domains_test, data_blob_codec_test, evaluate_blob_in_evaluation_form_test,
+        kzg_settings_dimensions_test, kzg_to_versioned_hash_test, serialize_proofs_into_test,
+        test_vectors_blob_to_kzg_commitment, test_vectors_compute_blob_kzg_proof,
+        test_vectors_compute_kzg_proof, test_vectors_verify_blob_kzg_proof,
+        test_vectors_verify_blob_kzg_proof_batch, test_vectors_verify_kzg_proof,
+        verify_blob_bundle_test, verify_blob_kzg_proof_batch_cancellable_test,
+        verify_blob_kzg_proof_batch_single_blob_missing_commitment_test,
+        verify_identity_strict_test, verify_kzg_proof_batch_fails_with_incorrect_proof_test,
+        verify_kzg_proof_batch_same_commitment_matches_general_test,
+        verify_kzg_proof_batch_same_commitment_test, verify_kzg_proof_batch_test,
+        verify_point_evaluation_precompile_test, zero_and_constant_blob_test,
     };
     use rust_kzg_zkcrypto::consts::SCALE2_ROOT_OF_UNITY;
     use rust_kzg_zkcrypto::eip_4844::load_trusted_setup_filename_rust;
@@ -36,6 +50,11 @@ mod tests {
         compute_powers_test::<ZFr>(&compute_powers);
     }
 
+    #[test]
+    pub fn compute_r_powers_degenerate_challenge() {
+        compute_r_powers_degenerate_challenge_test::<ZFr, ZG1>();
+    }
+
     #[test]
     pub fn blob_to_kzg_commitment_test_() {
         blob_to_kzg_commitment_test::<
@@ -312,6 +331,48 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn compute_blob_kzg_proof_incorrect_blob_length() {
+        compute_blob_kzg_proof_incorrect_blob_length_test::<
+            PolyData,
+            ZFr,
+            ZG1,
+            ZG2,
+            FFTSettings,
+            KZGSettings,
+            ZFp,
+            ZG1Affine,
+        >(&compute_blob_kzg_proof_rust)
+    }
+
+    #[test]
+    pub fn verify_blob_kzg_proof_batch_single_blob_missing_commitment() {
+        verify_blob_kzg_proof_batch_single_blob_missing_commitment_test::<
+            PolyData,
+            ZFr,
+            ZG1,
+            ZG2,
+            FFTSettings,
+            KZGSettings,
+            ZFp,
+            ZG1Affine,
+        >(&verify_blob_kzg_proof_batch_rust)
+    }
+
+    #[test]
+    pub fn kzg_settings_dimensions() {
+        kzg_settings_dimensions_test::<
+            PolyData,
+            ZFr,
+            ZG1,
+            ZG2,
+            FFTSettings,
+            KZGSettings,
+            ZFp,
+            ZG1Affine,
+        >(&load_trusted_setup_filename_rust)
+    }
+
     #[test]
     pub fn expand_root_of_unity_too_long() {
         let out = expand_root_of_unity(&ZFr::from_u64_arr(&SCALE2_ROOT_OF_UNITY[1]), 1);
@@ -323,4 +384,222 @@ mod tests {
         let out = expand_root_of_unity(&ZFr::from_u64_arr(&SCALE2_ROOT_OF_UNITY[1]), 3);
         assert!(out.is_err());
     }
+
+    #[test]
+    pub fn verify_identity_strict_test_() {
+        verify_identity_strict_test::<
+            ZFr,
+            ZG1,
+            ZG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ZFp,
+            ZG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn zero_and_constant_blob_test_() {
+        zero_and_constant_blob_test::<
+            ZFr,
+            ZG1,
+            ZG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ZFp,
+            ZG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn verify_point_evaluation_precompile_test_() {
+        verify_point_evaluation_precompile_test::<
+            ZFr,
+            ZG1,
+            ZG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ZFp,
+            ZG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn aggregate_kzg_proof_test_() {
+        aggregate_kzg_proof_test::<ZFr, ZG1, ZG2, PolyData, FFTSettings, KZGSettings, ZFp, ZG1Affine>(
+            &load_trusted_setup_filename_rust,
+        );
+    }
+
+    #[test]
+    pub fn verify_kzg_proof_batch_same_commitment_test_() {
+        verify_kzg_proof_batch_same_commitment_test::<
+            ZFr,
+            ZG1,
+            ZG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ZFp,
+            ZG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn verify_kzg_proof_batch_same_commitment_matches_general_test_() {
+        verify_kzg_proof_batch_same_commitment_matches_general_test::<
+            ZFr,
+            ZG1,
+            ZG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ZFp,
+            ZG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn custom_domains_test_() {
+        custom_domains_test::<ZFr, ZG1, ZG2, PolyData, FFTSettings, KZGSettings, ZFp, ZG1Affine>(
+            &load_trusted_setup_filename_rust,
+        );
+    }
+
+    #[test]
+    pub fn blob_to_kzg_commitment_batch_test_() {
+        blob_to_kzg_commitment_batch_test::<
+            ZFr,
+            ZG1,
+            ZG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ZFp,
+            ZG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn blob_to_kzg_commitment_streaming_test_() {
+        blob_to_kzg_commitment_streaming_test::<
+            ZFr,
+            ZG1,
+            ZG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ZFp,
+            ZG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn compute_blob_kzg_proof_streaming_test_() {
+        compute_blob_kzg_proof_streaming_test::<
+            ZFr,
+            ZG1,
+            ZG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ZFp,
+            ZG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn blob_newtype_test_() {
+        blob_newtype_test::<ZFr, ZG1, ZG2, PolyData, FFTSettings, KZGSettings, ZFp, ZG1Affine>(
+            &load_trusted_setup_filename_rust,
+        );
+    }
+
+    #[test]
+    pub fn blob_newtype_batch_apis_test_() {
+        blob_newtype_batch_apis_test::<
+            ZFr,
+            ZG1,
+            ZG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ZFp,
+            ZG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn verify_blob_bundle_test_() {
+        verify_blob_bundle_test::<ZFr, ZG1, ZG2, PolyData, FFTSettings, KZGSettings, ZFp, ZG1Affine>(
+            &load_trusted_setup_filename_rust,
+        );
+    }
+
+    #[test]
+    pub fn build_blob_sidecars_test_() {
+        build_blob_sidecars_test::<ZFr, ZG1, ZG2, PolyData, FFTSettings, KZGSettings, ZFp, ZG1Affine>(
+            &load_trusted_setup_filename_rust,
+        );
+    }
+
+    #[test]
+    pub fn evaluate_blob_in_evaluation_form_test_() {
+        evaluate_blob_in_evaluation_form_test::<
+            ZFr,
+            ZG1,
+            ZG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ZFp,
+            ZG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn kzg_to_versioned_hash_test_() {
+        kzg_to_versioned_hash_test::<ZG1>();
+    }
+
+    #[test]
+    pub fn serialize_proofs_into_test_() {
+        serialize_proofs_into_test::<ZFr, ZG1>();
+    }
+
+    #[test]
+    #[cfg(feature = "ssz")]
+    pub fn ssz_round_trip_test_() {
+        ssz_round_trip_test::<ZFr, ZG1>();
+    }
+
+    #[test]
+    pub fn data_blob_codec_test_() {
+        data_blob_codec_test::<ZFr>();
+    }
+
+    #[test]
+    #[cfg(feature = "commitment-cache")]
+    pub fn commitment_cache_test_() {
+        commitment_cache_test::<ZFr, ZG1, ZG2, PolyData, FFTSettings, KZGSettings, ZFp, ZG1Affine>(
+            &load_trusted_setup_filename_rust,
+        );
+    }
+
+    #[test]
+    pub fn verify_blob_kzg_proof_batch_cancellable_test_() {
+        verify_blob_kzg_proof_batch_cancellable_test::<
+            ZFr,
+            ZG1,
+            ZG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ZFp,
+            ZG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
 }
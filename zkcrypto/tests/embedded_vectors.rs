@@ -0,0 +1,22 @@
+#[cfg(test)]
+mod tests {
+    use kzg_bench::tests::embedded_vectors::eip_4844_embedded_vectors_test;
+    use rust_kzg_zkcrypto::eip_4844::load_trusted_setup_filename_rust;
+    use rust_kzg_zkcrypto::kzg_proofs::{FFTSettings, KZGSettings};
+    use rust_kzg_zkcrypto::kzg_types::{ZFp, ZFr, ZG1Affine, ZG1, ZG2};
+    use rust_kzg_zkcrypto::poly::PolyData;
+
+    #[test]
+    pub fn eip_4844_embedded_vectors() {
+        eip_4844_embedded_vectors_test::<
+            ZFr,
+            ZG1,
+            ZG2,
+            FFTSettings,
+            PolyData,
+            KZGSettings,
+            ZFp,
+            ZG1Affine,
+        >(&load_trusted_setup_filename_rust);
+    }
+}
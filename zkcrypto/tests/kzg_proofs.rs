@@ -1,7 +1,8 @@
 #[cfg(test)]
 mod tests {
     use kzg_bench::tests::kzg_proofs::{
-        commit_to_nil_poly, commit_to_too_long_poly_returns_err, proof_multi, proof_single,
+        commit_monomial_degree_bound, commit_to_nil_poly, commit_to_too_long_poly_returns_err,
+        get_g2_generator_matches_generator, proof_multi, proof_single, with_shared_points_settings,
     };
     use rust_kzg_zkcrypto::kzg_proofs::{generate_trusted_setup, FFTSettings, KZGSettings};
     use rust_kzg_zkcrypto::kzg_types::{ZFp, ZFr, ZG1Affine, ZG1, ZG2};
@@ -33,10 +34,52 @@ mod tests {
         >(&generate_trusted_setup);
     }
 
+    #[test]
+    fn commit_monomial_degree_bound_() {
+        commit_monomial_degree_bound::<
+            ZFr,
+            ZG1,
+            ZG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ZFp,
+            ZG1Affine,
+        >(&generate_trusted_setup);
+    }
+
     #[test]
     fn proof_multi_() {
         proof_multi::<ZFr, ZG1, ZG2, PolyData, FFTSettings, KZGSettings, ZFp, ZG1Affine>(
             &generate_trusted_setup,
         );
     }
+
+    #[test]
+    fn with_shared_points_settings_() {
+        with_shared_points_settings::<
+            ZFr,
+            ZG1,
+            ZG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ZFp,
+            ZG1Affine,
+        >(&generate_trusted_setup);
+    }
+
+    #[test]
+    fn get_g2_generator_matches_generator_() {
+        get_g2_generator_matches_generator::<
+            ZFr,
+            ZG1,
+            ZG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ZFp,
+            ZG1Affine,
+        >(&generate_trusted_setup);
+    }
 }